@@ -189,6 +189,17 @@ unsafe impl PackedField for Avx2GoldilocksField {
         };
         (Self::new(res0), Self::new(res1))
     }
+
+    #[inline]
+    fn from_strided(slice: &[Self::Scalar], stride: usize) -> Self {
+        assert!(
+            slice.len() > (Self::WIDTH - 1) * stride,
+            "Slice too short for strided gather"
+        );
+        let stride = stride as i64;
+        let offsets = unsafe { _mm256_setr_epi64x(0, stride, 2 * stride, 3 * stride) };
+        Self::new(unsafe { _mm256_i64gather_epi64::<8>(slice.as_ptr().cast(), offsets) })
+    }
 }
 
 impl Square for Avx2GoldilocksField {
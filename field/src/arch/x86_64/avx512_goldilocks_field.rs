@@ -71,6 +71,28 @@ unsafe impl PackedField for Avx512GoldilocksField {
         };
         (Self::new(res0), Self::new(res1))
     }
+
+    #[inline]
+    fn from_strided(slice: &[Self::Scalar], stride: usize) -> Self {
+        assert!(
+            slice.len() > (Self::WIDTH - 1) * stride,
+            "Slice too short for strided gather"
+        );
+        let stride = stride as i64;
+        let offsets = unsafe {
+            _mm512_setr_epi64(
+                0,
+                stride,
+                2 * stride,
+                3 * stride,
+                4 * stride,
+                5 * stride,
+                6 * stride,
+                7 * stride,
+            )
+        };
+        Self::new(unsafe { _mm512_i64gather_epi64::<8>(offsets, slice.as_ptr().cast()) })
+    }
 }
 
 impl Add<Self> for Avx512GoldilocksField {
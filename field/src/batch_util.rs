@@ -63,3 +63,53 @@ pub fn batch_add_inplace<F: Field>(out: &mut [F], a: &[F]) {
         *x_out += *x_a;
     }
 }
+
+/// Computes the inner product `sum_i c_i * v_i` of two slices of field elements.
+/// Implementation be faster than the trivial for loop.
+pub fn dot_product<F: Field>(c: &[F], v: &[F]) -> F {
+    let n = c.len();
+    assert_eq!(n, v.len(), "both arrays must have the same length");
+
+    // Split out slice of vectors, leaving leftovers as scalars
+    let (c_packed, c_leftovers) = pack_slice_with_leftovers::<<F as Packable>::Packing>(c);
+    let (v_packed, v_leftovers) = pack_slice_with_leftovers::<<F as Packable>::Packing>(v);
+
+    // Accumulate packed and the leftovers
+    let packed_sum = packed_dot_product(c_packed, v_packed);
+    c_leftovers
+        .iter()
+        .zip(v_leftovers)
+        .fold(packed_sum, |acc, (&x_c, &x_v)| acc + x_c * x_v)
+}
+
+/// Computes the inner product of two slices of packed field elements, accumulating lane-wise and
+/// summing the lanes at the end.
+pub fn packed_dot_product<P: PackedField>(c: &[P], v: &[P]) -> P::Scalar {
+    assert_eq!(c.len(), v.len(), "both arrays must have the same length");
+    let acc = c
+        .iter()
+        .zip(v)
+        .fold(P::ZEROS, |acc, (&x_c, &x_v)| acc + x_c * x_v);
+    acc.as_slice().iter().copied().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks_field::GoldilocksField;
+    use crate::types::Sample;
+
+    #[test]
+    fn test_dot_product() {
+        type F = GoldilocksField;
+        for n in [0, 1, 7, 8, 33, 1 << 10] {
+            let c = F::rand_vec(n);
+            let v = F::rand_vec(n);
+            let expected = c
+                .iter()
+                .zip(&v)
+                .fold(F::ZERO, |acc, (&x_c, &x_v)| acc + x_c * x_v);
+            assert_eq!(dot_product(&c, &v), expected);
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use alloc::vec::Vec;
 
 use num::bigint::BigUint;
+use plonky2_util::log2_strict;
 
+use crate::fft::{fft_root_table, FftRootTable};
+use crate::polynomial::{PolynomialCoeffs, PolynomialValues};
 use crate::types::Field;
 
 /// Finds a set of shifts that result in unique cosets for the multiplicative subgroup of size
@@ -23,13 +26,73 @@ pub fn get_unique_coset_shifts<F: Field>(subgroup_size: usize, num_shifts: usize
         .collect()
 }
 
+/// The coset `shift * H` of the multiplicative subgroup `H` of size `2^log_size`. The points of
+/// the coset and the FFT root table are computed once, so a domain can be reused to extend many
+/// polynomials of the same size, across columns and across proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosetDomain<F: Field> {
+    shift: F,
+    points: Vec<F>,
+    root_table: FftRootTable<F>,
+}
+
+impl<F: Field> CosetDomain<F> {
+    /// The domain of size `2^log_size` shifted by `F::coset_shift()`, as used for LDEs.
+    pub fn new(log_size: usize) -> Self {
+        Self::with_shift(log_size, F::coset_shift())
+    }
+
+    pub fn with_shift(log_size: usize, shift: F) -> Self {
+        let size = 1 << log_size;
+        let generator = F::primitive_root_of_unity(log_size);
+        Self {
+            shift,
+            points: F::cyclic_subgroup_coset_known_order(generator, shift, size),
+            root_table: fft_root_table(size),
+        }
+    }
+
+    pub fn lde_shift(&self) -> F {
+        self.shift
+    }
+
+    /// The points `shift * g^i` of the domain, in order of `i`.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn root_table(&self) -> &FftRootTable<F> {
+        &self.root_table
+    }
+
+    /// Evaluates `poly` at every point of the domain. The length of `poly` must be a power of two
+    /// which is at most the size of the domain.
+    pub fn lde(&self, poly: &PolynomialCoeffs<F>) -> PolynomialValues<F> {
+        let rate_bits = log2_strict(self.len()) - log2_strict(poly.len());
+        poly.lde(rate_bits).coset_fft_with_options(
+            self.shift,
+            Some(rate_bits),
+            Some(&self.root_table),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
-    use crate::cosets::get_unique_coset_shifts;
+    use crate::cosets::{get_unique_coset_shifts, CosetDomain};
     use crate::goldilocks_field::GoldilocksField;
-    use crate::types::Field;
+    use crate::polynomial::PolynomialCoeffs;
+    use crate::types::{Field, Sample};
 
     #[test]
     fn distinct_cosets() {
@@ -51,4 +114,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn coset_domain() {
+        type F = GoldilocksField;
+        const LOG_SIZE: usize = 6;
+        const RATE_BITS: usize = 2;
+
+        let domain = CosetDomain::<F>::new(LOG_SIZE);
+        assert_eq!(domain.lde_shift(), F::coset_shift());
+        assert_eq!(
+            domain.points(),
+            F::cyclic_subgroup_coset_known_order(
+                F::primitive_root_of_unity(LOG_SIZE),
+                F::coset_shift(),
+                1 << LOG_SIZE
+            )
+        );
+
+        for _ in 0..4 {
+            let poly = PolynomialCoeffs::new(F::rand_vec(1 << (LOG_SIZE - RATE_BITS)));
+            let expected = poly.lde(RATE_BITS).coset_fft(F::coset_shift());
+            assert_eq!(domain.lde(&poly), expected);
+            for (&x, &y) in domain.points().iter().zip(&expected.values) {
+                assert_eq!(poly.eval(x), y);
+            }
+        }
+    }
 }
@@ -59,6 +59,20 @@ pub trait Frobenius<const D: usize>: OEF<D> {
 
         Self::from_basefield_array(res)
     }
+
+    /// The field norm of `self` over `BaseField`, i.e. the product of its Frobenius conjugates.
+    fn norm(&self) -> Self::BaseField {
+        let norm = (1..D).fold(*self, |acc, i| acc * self.repeated_frobenius(i));
+        debug_assert!(norm.is_in_basefield());
+        norm.to_basefield_array()[0]
+    }
+
+    /// The field trace of `self` over `BaseField`, i.e. the sum of its Frobenius conjugates.
+    fn trace(&self) -> Self::BaseField {
+        let trace = (1..D).fold(*self, |acc, i| acc + self.repeated_frobenius(i));
+        debug_assert!(trace.is_in_basefield());
+        trace.to_basefield_array()[0]
+    }
 }
 
 pub trait Extendable<const D: usize>: Field + Sized {
@@ -106,6 +120,19 @@ pub trait FieldExtension<const D: usize>: Field {
         });
         Self::from_basefield_array(res)
     }
+
+    /// Embeds each base field element of `xs` into the extension.
+    fn from_base_slice(xs: &[Self::BaseField]) -> Vec<Self> {
+        xs.iter().map(|&x| Self::from_basefield(x)).collect()
+    }
+
+    /// Projects each element of `xs` onto the base field, or returns `None` if any of them is not
+    /// in the base field.
+    fn try_to_base_slice(xs: &[Self]) -> Option<Vec<Self::BaseField>> {
+        xs.iter()
+            .map(|x| x.is_in_basefield().then(|| x.to_basefield_array()[0]))
+            .collect()
+    }
 }
 
 impl<F: Field> FieldExtension<1> for F {
@@ -144,3 +171,38 @@ where
         .map(|c| F::Extension::from_basefield_array(c.to_vec().try_into().unwrap()))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks_field::GoldilocksField;
+    use crate::types::Sample;
+
+    #[test]
+    fn test_base_slice_round_trip() {
+        type F = GoldilocksField;
+        type FE = <F as Extendable<2>>::Extension;
+
+        let xs = F::rand_vec(10);
+        let embedded = <FE as FieldExtension<2>>::from_base_slice(&xs);
+        assert_eq!(embedded.len(), xs.len());
+        assert_eq!(
+            <FE as FieldExtension<2>>::try_to_base_slice(&embedded),
+            Some(xs)
+        );
+        assert_eq!(
+            <FE as FieldExtension<2>>::try_to_base_slice(&[]),
+            Some(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_try_to_base_slice_rejects_extension_elements() {
+        type F = GoldilocksField;
+        type FE = <F as Extendable<2>>::Extension;
+
+        let mut xs = <FE as FieldExtension<2>>::from_base_slice(&F::rand_vec(10));
+        xs[3] = <FE as FieldExtension<2>>::from_basefield_array([F::ONE, F::ONE]);
+        assert_eq!(<FE as FieldExtension<2>>::try_to_base_slice(&xs), None);
+    }
+}
@@ -60,22 +60,31 @@ pub fn fft_with_options<F: Field>(
     PolynomialValues::new(buffer)
 }
 
+/// Replaces the coefficients in `buffer` with the polynomial's evaluations over the subgroup of
+/// order `buffer.len()`, without allocating. Both the input coefficients and the output values are
+/// in natural order, as with `fft`; the bit reversal is handled internally.
 #[inline]
-pub fn ifft<F: Field>(poly: PolynomialValues<F>) -> PolynomialCoeffs<F> {
-    ifft_with_options(poly, None, None)
+pub fn fft_in_place<F: Field>(buffer: &mut [F], root_table: Option<&FftRootTable<F>>) {
+    fft_dispatch(buffer, None, root_table);
 }
 
-pub fn ifft_with_options<F: Field>(
-    poly: PolynomialValues<F>,
+/// Replaces the evaluations in `buffer` with the coefficients of the interpolating polynomial,
+/// without allocating. Both the input values and the output coefficients are in natural order, as
+/// with `ifft`.
+pub fn ifft_in_place<F: Field>(buffer: &mut [F], root_table: Option<&FftRootTable<F>>) {
+    ifft_in_place_with_zero_factor(buffer, None, root_table);
+}
+
+fn ifft_in_place_with_zero_factor<F: Field>(
+    buffer: &mut [F],
     zero_factor: Option<usize>,
     root_table: Option<&FftRootTable<F>>,
-) -> PolynomialCoeffs<F> {
-    let n = poly.len();
+) {
+    let n = buffer.len();
     let lg_n = log2_strict(n);
     let n_inv = F::inverse_2exp(lg_n);
 
-    let PolynomialValues { values: mut buffer } = poly;
-    fft_dispatch(&mut buffer, zero_factor, root_table);
+    fft_dispatch(buffer, zero_factor, root_table);
 
     // We reverse all values except the first, and divide each by n.
     buffer[0] *= n_inv;
@@ -87,9 +96,43 @@ pub fn ifft_with_options<F: Field>(
         buffer[i] = coeffs_i;
         buffer[j] = coeffs_j;
     }
+}
+
+#[inline]
+pub fn ifft<F: Field>(poly: PolynomialValues<F>) -> PolynomialCoeffs<F> {
+    ifft_with_options(poly, None, None)
+}
+
+pub fn ifft_with_options<F: Field>(
+    poly: PolynomialValues<F>,
+    zero_factor: Option<usize>,
+    root_table: Option<&FftRootTable<F>>,
+) -> PolynomialCoeffs<F> {
+    let PolynomialValues { values: mut buffer } = poly;
+    ifft_in_place_with_zero_factor(&mut buffer, zero_factor, root_table);
     PolynomialCoeffs { coeffs: buffer }
 }
 
+/// Interpolates the polynomial whose evaluations on the coset `shift*H` are `poly`. This is the
+/// inverse of `PolynomialCoeffs::coset_fft_with_options`, and the same root table can be used for
+/// both directions.
+pub fn coset_ifft_with_options<F: Field>(
+    poly: PolynomialValues<F>,
+    shift: F,
+    zero_factor: Option<usize>,
+    root_table: Option<&FftRootTable<F>>,
+) -> PolynomialCoeffs<F> {
+    let mut shifted_coeffs = ifft_with_options(poly, zero_factor, root_table);
+    shifted_coeffs
+        .coeffs
+        .iter_mut()
+        .zip(shift.inverse().powers())
+        .for_each(|(c, r)| {
+            *c *= r;
+        });
+    shifted_coeffs
+}
+
 /// Generic FFT implementation that works with both scalar and packed inputs.
 #[unroll_for_loops]
 fn fft_classic_simd<P: PackedField>(
@@ -207,10 +250,13 @@ mod tests {
 
     use plonky2_util::{log2_ceil, log2_strict};
 
-    use crate::fft::{fft, fft_with_options, ifft};
+    use crate::fft::{
+        coset_ifft_with_options, fft, fft_in_place, fft_root_table, fft_with_options, ifft,
+        ifft_in_place,
+    };
     use crate::goldilocks_field::GoldilocksField;
     use crate::polynomial::{PolynomialCoeffs, PolynomialValues};
-    use crate::types::Field;
+    use crate::types::{Field, Sample};
 
     #[test]
     fn fft_and_ifft() {
@@ -248,6 +294,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fft_and_ifft_in_place() {
+        type F = GoldilocksField;
+        for lg_n in [0, 1, 3, 8] {
+            let n = 1 << lg_n;
+            let root_table = fft_root_table::<F>(n);
+            let coeffs = F::rand_vec(n);
+
+            let mut buffer = coeffs.clone();
+            fft_in_place(&mut buffer, Some(&root_table));
+            let values = fft(PolynomialCoeffs::new(coeffs.clone()));
+            assert_eq!(buffer, values.values);
+
+            ifft_in_place(&mut buffer, None);
+            assert_eq!(buffer, ifft(values).coeffs);
+            assert_eq!(buffer, coeffs);
+        }
+    }
+
+    #[test]
+    fn coset_fft_and_ifft_with_options() {
+        type F = GoldilocksField;
+        let lg_n = 6;
+        let n = 1 << lg_n;
+        let shift = F::MULTIPLICATIVE_GROUP_GENERATOR;
+        let root_table = fft_root_table::<F>(n);
+
+        let coeffs = PolynomialCoeffs::new(F::rand_vec(n));
+        let values = coeffs.coset_fft_with_options(shift, None, Some(&root_table));
+        for (i, x) in
+            F::cyclic_subgroup_coset_known_order(F::primitive_root_of_unity(lg_n), shift, n)
+                .into_iter()
+                .enumerate()
+        {
+            assert_eq!(values.values[i], evaluate_at_naive(&coeffs, x));
+        }
+
+        let interpolated_coeffs =
+            coset_ifft_with_options(values.clone(), shift, None, Some(&root_table));
+        assert_eq!(interpolated_coeffs, coeffs);
+        assert_eq!(
+            coset_ifft_with_options(values.clone(), shift, None, None),
+            interpolated_coeffs
+        );
+        assert_eq!(values.coset_ifft(shift), interpolated_coeffs);
+    }
+
     fn evaluate_naive<F: Field>(coefficients: &PolynomialCoeffs<F>) -> PolynomialValues<F> {
         let degree = coefficients.len();
         let degree_padded = 1 << log2_ceil(degree);
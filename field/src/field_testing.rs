@@ -156,6 +156,22 @@ pub(crate) fn test_frobenius<BF: Extendable<D>, const D: usize>() {
     }
 }
 
+pub(crate) fn test_norm_trace<BF: Extendable<D>, const D: usize>() {
+    let x = BF::Extension::rand();
+    let y = BF::Extension::rand();
+    let b = BF::rand();
+    assert_eq!((x * y).norm(), x.norm() * y.norm());
+    assert_eq!((x + y).trace(), x.trace() + y.trace());
+    assert_eq!(BF::Extension::from(b).norm(), b.exp_u64(D as u64));
+    assert_eq!(
+        BF::Extension::from(b).trace(),
+        b * BF::from_canonical_usize(D)
+    );
+    // The norm is also `x^((p^D - 1) / (p - 1))`.
+    let norm_exp = (BF::Extension::order() - 1u8) / (BF::order() - 1u8);
+    assert_eq!(x.exp_biguint(&norm_exp), x.norm().into());
+}
+
 pub(crate) fn test_field_order<BF: Extendable<D>, const D: usize>() {
     let x = BF::Extension::rand();
     assert_eq!(
@@ -194,6 +210,10 @@ macro_rules! test_field_extension {
                 $crate::field_testing::test_frobenius::<$field, $d>();
             }
             #[test]
+            fn test_norm_trace() {
+                $crate::field_testing::test_norm_trace::<$field, $d>();
+            }
+            #[test]
             fn test_field_order() {
                 $crate::field_testing::test_field_order::<$field, $d>();
             }
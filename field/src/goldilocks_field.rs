@@ -192,6 +192,43 @@ impl PrimeField for GoldilocksField {
     fn to_canonical_biguint(&self) -> BigUint {
         self.to_canonical_u64().into()
     }
+
+    fn is_quadratic_residue(&self) -> bool {
+        // Euler's criterion, with the exponent computed in `u64` rather than `BigUint`.
+        self.is_zero() || self.exp_u64((Self::ORDER - 1) / 2).is_one()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        if !self.is_quadratic_residue() {
+            return None;
+        }
+
+        // Tonelli-Shanks, writing `p - 1 = 2^TWO_ADICITY * t` with `t` odd.
+        let t = (Self::ORDER - 1) >> Self::TWO_ADICITY;
+        let mut z = Self::POWER_OF_TWO_GENERATOR;
+        let mut w = self.exp_u64((t - 1) / 2);
+        let mut x = w * *self;
+        let mut b = x * w;
+        let mut v = Self::TWO_ADICITY;
+
+        while !b.is_one() {
+            let mut k = 0;
+            let mut b2k = b;
+            while !b2k.is_one() {
+                b2k = b2k.square();
+                k += 1;
+            }
+            w = z.exp_power_of_2(v - k - 1);
+            z = w.square();
+            b *= z;
+            x *= w;
+            v = k;
+        }
+        Some(x)
+    }
 }
 
 impl Field64 for GoldilocksField {
@@ -456,8 +493,42 @@ fn exp_acc<const N: usize>(base: GoldilocksField, tail: GoldilocksField) -> Gold
 
 #[cfg(test)]
 mod tests {
+    use crate::goldilocks_field::GoldilocksField;
+    use crate::ops::Square;
+    use crate::types::{Field, PrimeField, Sample};
     use crate::{test_field_arithmetic, test_prime_field_arithmetic};
 
     test_prime_field_arithmetic!(crate::goldilocks_field::GoldilocksField);
     test_field_arithmetic!(crate::goldilocks_field::GoldilocksField);
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(F::ZERO.sqrt(), Some(F::ZERO));
+        assert_eq!(F::ONE.sqrt().map(|r| r.square()), Some(F::ONE));
+
+        for x in F::rand_vec(100) {
+            let square = x.square();
+            assert!(square.is_quadratic_residue());
+            let root = square.sqrt().expect("squares should have square roots");
+            assert_eq!(root.square(), square);
+        }
+    }
+
+    #[test]
+    fn test_non_residues() {
+        // The multiplicative group generator is never a square.
+        let g = F::MULTIPLICATIVE_GROUP_GENERATOR;
+        assert!(!g.is_quadratic_residue());
+        assert_eq!(g.sqrt(), None);
+
+        let samples = F::rand_vec(1000);
+        let num_residues = samples.iter().filter(|x| x.is_quadratic_residue()).count();
+        // About half of nonzero elements are residues.
+        assert!((400..=600).contains(&num_residues));
+        for x in samples {
+            assert_eq!(x.sqrt().is_some(), x.is_quadratic_residue());
+        }
+    }
 }
@@ -93,6 +93,23 @@ where
         unsafe { slice::from_raw_parts_mut(buf_ptr, n) }
     }
 
+    /// Gathers `WIDTH` scalars from `slice`, `stride` elements apart, starting at `slice[0]`.
+    /// This is useful to pack a column of a row-major matrix whose rows have length `stride`.
+    fn from_strided(slice: &[Self::Scalar], stride: usize) -> Self {
+        assert!(
+            slice.len() > (Self::WIDTH - 1) * stride,
+            "Slice length (got {}) too short for {} elements with stride {}.",
+            slice.len(),
+            Self::WIDTH,
+            stride
+        );
+        let mut res = Self::ZEROS;
+        for (i, x) in res.as_slice_mut().iter_mut().enumerate() {
+            *x = slice[i * stride];
+        }
+        res
+    }
+
     fn doubles(&self) -> Self {
         *self * Self::Scalar::TWO
     }
@@ -125,3 +142,31 @@ unsafe impl<F: Field> PackedField for F {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks_field::GoldilocksField;
+    use crate::packable::Packable;
+    use crate::types::Sample;
+
+    fn check_from_strided<P: PackedField>() {
+        for stride in [1, 2, 7, 50] {
+            let matrix = P::Scalar::rand_vec(P::WIDTH * stride + 3);
+            for start in 0..stride {
+                let packed = P::from_strided(&matrix[start..], stride);
+                let expected = (0..P::WIDTH)
+                    .map(|i| matrix[start + i * stride])
+                    .collect::<Vec<_>>();
+                assert_eq!(packed.as_slice(), &expected[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_strided() {
+        type F = GoldilocksField;
+        check_from_strided::<F>();
+        check_from_strided::<<F as Packable>::Packing>();
+    }
+}
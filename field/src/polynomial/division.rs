@@ -1,6 +1,7 @@
 use alloc::vec;
 use alloc::vec::Vec;
 
+use anyhow::{ensure, Result};
 use plonky2_util::log2_ceil;
 
 use crate::polynomial::PolynomialCoeffs;
@@ -87,6 +88,32 @@ impl<F: Field> PolynomialCoeffs<F> {
         Self { coeffs: bs }
     }
 
+    /// Divides `self` by the vanishing polynomial `X^n - 1` of the order-`n` subgroup, in linear
+    /// time. Returns an error if the division is not exact.
+    pub fn divide_by_z_h(&self, n: usize) -> Result<PolynomialCoeffs<F>> {
+        ensure!(n > 0, "`n` needs to be nonzero");
+
+        // Writing `self = q(X) (X^n - 1)` gives `p_k = q_{k-n} - q_k`, so the quotient can be
+        // recovered from the lowest coefficient upwards. The top `n` coefficients are then fully
+        // determined by the quotient, which lets us check that there is no remainder.
+        let len = self.len();
+        let quotient_len = len.saturating_sub(n);
+        let mut quotient = Vec::with_capacity(quotient_len);
+        for k in 0..quotient_len {
+            let prev = if k >= n { quotient[k - n] } else { F::ZERO };
+            quotient.push(prev - self.coeffs[k]);
+        }
+        for k in quotient_len..len {
+            let expected = if k >= n { quotient[k - n] } else { F::ZERO };
+            ensure!(
+                self.coeffs[k] == expected,
+                "Polynomial is not divisible by X^{} - 1",
+                n
+            );
+        }
+        Ok(Self::new(quotient))
+    }
+
     /// Computes the inverse of `self` modulo `x^n`.
     pub fn inv_mod_xn(&self, n: usize) -> Self {
         assert!(n > 0, "`n` needs to be nonzero");
@@ -122,9 +149,9 @@ impl<F: Field> PolynomialCoeffs<F> {
             tmp.trim();
             let mut b = &a * &tmp;
             b.trim();
-            if b.len() > l {
-                b.coeffs.drain(l..);
-            }
+            // `b` holds the next `l` coefficients of the inverse, including any trailing zeros
+            // removed by `trim`.
+            b.coeffs.resize(l, F::ZERO);
             a.coeffs.extend_from_slice(&b.coeffs);
         }
         a.coeffs.drain(n..);
@@ -156,4 +183,24 @@ mod tests {
             &(&quotient * &vec![-z, F::ONE].into()) + &vec![ev].into() // `quotient * (X-z) + ev`
         );
     }
+
+    #[test]
+    fn test_divide_by_z_h() {
+        type F = GoldilocksField;
+        for (n, quotient_len) in [(1, 10), (4, 3), (8, 64), (16, 100)] {
+            let quotient = PolynomialCoeffs::new(F::rand_vec(quotient_len));
+            let mut z_h = PolynomialCoeffs::zero(n + 1);
+            z_h.coeffs[0] = F::NEG_ONE;
+            z_h.coeffs[n] = F::ONE;
+            let poly = (&quotient * &z_h).trimmed();
+
+            let (expected, remainder) = poly.div_rem(&z_h);
+            assert!(remainder.is_zero());
+            assert_eq!(poly.divide_by_z_h(n).unwrap().trimmed(), expected.trimmed());
+            assert_eq!(poly.divide_by_z_h(n).unwrap().trimmed(), quotient.trimmed());
+
+            let not_divisible = &poly + &PolynomialCoeffs::new(vec![F::ONE]);
+            assert!(not_divisible.divide_by_z_h(n).is_err());
+        }
+    }
 }
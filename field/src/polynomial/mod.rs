@@ -1,4 +1,5 @@
 pub(crate) mod division;
+mod sparse;
 
 use alloc::vec;
 use alloc::vec::Vec;
@@ -11,8 +12,9 @@ use itertools::Itertools;
 use plonky2_util::log2_strict;
 use serde::{Deserialize, Serialize};
 
+pub use self::sparse::SparsePolynomial;
 use crate::extension::{Extendable, FieldExtension};
-use crate::fft::{fft, fft_with_options, ifft, FftRootTable};
+use crate::fft::{coset_ifft_with_options, fft, fft_with_options, ifft, FftRootTable};
 use crate::types::Field;
 
 /// A polynomial in point-value form.
@@ -61,15 +63,7 @@ impl<F: Field> PolynomialValues<F> {
 
     /// Returns the polynomial whose evaluation on the coset `shift*H` is `self`.
     pub fn coset_ifft(self, shift: F) -> PolynomialCoeffs<F> {
-        let mut shifted_coeffs = self.ifft();
-        shifted_coeffs
-            .coeffs
-            .iter_mut()
-            .zip(shift.inverse().powers())
-            .for_each(|(c, r)| {
-                *c *= r;
-            });
-        shifted_coeffs
+        coset_ifft_with_options(self, shift, None, None)
     }
 
     pub fn lde_multiple(polys: Vec<Self>, rate_bits: usize) -> Vec<Self> {
@@ -152,6 +146,23 @@ impl<F: Field> PolynomialCoeffs<F> {
             .collect()
     }
 
+    /// Splits the polynomial into `num_pieces` pieces of `ceil(len / num_pieces)` coefficients
+    /// each, padding the last pieces with zeros, so that
+    /// `self(X) = sum_i X^(i * chunk_size) * piece_i(X)`.
+    pub fn split_into(&self, num_pieces: usize) -> Vec<Self> {
+        assert!(num_pieces > 0, "Cannot split a polynomial into zero pieces");
+        let chunk_size = self.len().div_ceil(num_pieces);
+        (0..num_pieces)
+            .map(|i| {
+                let start = (i * chunk_size).min(self.len());
+                let end = ((i + 1) * chunk_size).min(self.len());
+                let mut coeffs = self.coeffs[start..end].to_vec();
+                coeffs.resize(chunk_size, F::ZERO);
+                PolynomialCoeffs::new(coeffs)
+            })
+            .collect()
+    }
+
     pub fn eval(&self, x: F) -> F {
         self.coeffs
             .iter()
@@ -159,6 +170,22 @@ impl<F: Field> PolynomialCoeffs<F> {
             .fold(F::ZERO, |acc, &c| acc * x + c)
     }
 
+    /// Evaluates each of `polys` at the common point `x`. The powers of `x` are computed once and
+    /// shared by all polynomials.
+    pub fn eval_many_at(polys: &[&Self], x: F) -> Vec<F> {
+        let max_len = polys.iter().map(|p| p.len()).max().unwrap_or(0);
+        let powers = x.powers().take(max_len).collect::<Vec<_>>();
+        polys
+            .iter()
+            .map(|p| {
+                p.coeffs
+                    .iter()
+                    .zip(&powers)
+                    .fold(F::ZERO, |acc, (&c, &power)| acc + c * power)
+            })
+            .collect()
+    }
+
     /// Evaluate the polynomial at a point given its powers. The first power is the point itself, not 1.
     pub fn eval_with_powers(&self, powers: &[F]) -> F {
         debug_assert_eq!(self.coeffs.len(), powers.len() + 1);
@@ -292,6 +319,25 @@ impl<F: Field> PolynomialCoeffs<F> {
         modified_poly.fft_with_options(zero_factor, root_table)
     }
 
+    /// Interpolates the polynomial whose evaluations on the coset `shift*H` are `values`. This is
+    /// the inverse of `coset_fft_with_options`, and the same as `PolynomialValues::coset_ifft`.
+    pub fn interpolate_coset(
+        values: &PolynomialValues<F>,
+        shift: F,
+        root_table: Option<&FftRootTable<F>>,
+    ) -> Self {
+        coset_ifft_with_options(values.clone(), shift, None, root_table)
+    }
+
+    /// Returns the evaluations of the polynomial at the points `shift * generator^i` for the given
+    /// indices `i`. This is cheaper than `coset_fft` when only a few points are needed.
+    pub fn eval_on_coset_points(&self, shift: F, generator: F, indices: &[usize]) -> Vec<F> {
+        indices
+            .iter()
+            .map(|&i| self.eval(shift * generator.exp_u64(i as u64)))
+            .collect()
+    }
+
     pub fn to_extension<const D: usize>(&self) -> PolynomialCoeffs<F::Extension>
     where
         F: Extendable<D>,
@@ -444,6 +490,7 @@ mod tests {
     use rand::Rng;
 
     use super::*;
+    use crate::fft::fft_root_table;
     use crate::goldilocks_field::GoldilocksField;
     use crate::types::Sample;
 
@@ -473,6 +520,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_assign_scaled() {
+        type F = GoldilocksField;
+
+        let n = 64;
+        let polys = (0..5)
+            .map(|_| PolynomialValues::new(F::rand_vec(n)))
+            .collect::<Vec<_>>();
+        let scalars = F::rand_vec(polys.len());
+
+        let mut acc = PolynomialValues::zero(n);
+        for (poly, &scalar) in polys.iter().zip(&scalars) {
+            acc.add_assign_scaled(poly, scalar);
+        }
+
+        let expected = polys
+            .iter()
+            .zip(&scalars)
+            .fold(vec![F::ZERO; n], |acc, (poly, &scalar)| {
+                acc.iter()
+                    .zip(&poly.values)
+                    .map(|(&a, &v)| a + scalar * v)
+                    .collect()
+            });
+        assert_eq!(acc.values, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_scaled_length_mismatch() {
+        type F = GoldilocksField;
+
+        let mut acc = PolynomialValues::<F>::zero(4);
+        acc.add_assign_scaled(&PolynomialValues::zero(8), F::ONE);
+    }
+
+    #[test]
+    fn test_eval_many_at() {
+        type F = GoldilocksField;
+
+        let polys = [0, 1, 5, 32, 7]
+            .into_iter()
+            .map(|len| PolynomialCoeffs::new(F::rand_vec(len)))
+            .collect::<Vec<_>>();
+        let poly_refs = polys.iter().collect::<Vec<_>>();
+        let x = F::rand();
+        assert_eq!(
+            PolynomialCoeffs::eval_many_at(&poly_refs, x),
+            polys.iter().map(|p| p.eval(x)).collect::<Vec<_>>()
+        );
+        assert!(PolynomialCoeffs::<F>::eval_many_at(&[], x).is_empty());
+    }
+
     #[test]
     fn test_coset_fft() {
         type F = GoldilocksField;
@@ -494,6 +594,24 @@ mod tests {
         assert_eq!(poly, ifft_coeffs);
     }
 
+    #[test]
+    fn test_eval_on_coset_points() {
+        type F = GoldilocksField;
+
+        let k = 8;
+        let n = 1 << k;
+        let poly = PolynomialCoeffs::new(F::rand_vec(n));
+        let shift = F::rand();
+        let coset_evals = poly.coset_fft(shift).values;
+
+        let generator = F::primitive_root_of_unity(k);
+        let indices = [0, 1, 2, 3, 100, n - 1];
+        let evals = poly.eval_on_coset_points(shift, generator, &indices);
+        for (&i, eval) in indices.iter().zip(evals) {
+            assert_eq!(eval, coset_evals[i]);
+        }
+    }
+
     #[test]
     fn test_coset_ifft() {
         type F = GoldilocksField;
@@ -515,6 +633,53 @@ mod tests {
         assert_eq!(evals, fft_evals);
     }
 
+    #[test]
+    fn test_interpolate_coset() {
+        type F = GoldilocksField;
+
+        let k = 8;
+        let n = 1 << k;
+        let shift = F::rand();
+        let root_table = fft_root_table(n);
+
+        let poly = PolynomialCoeffs::new(F::rand_vec(n));
+        let coset_evals = poly.coset_fft(shift);
+        assert_eq!(
+            PolynomialCoeffs::interpolate_coset(&coset_evals, shift, None),
+            poly
+        );
+        assert_eq!(
+            PolynomialCoeffs::interpolate_coset(&coset_evals, shift, Some(&root_table)),
+            poly
+        );
+
+        let evals = PolynomialValues::new(F::rand_vec(n));
+        assert_eq!(
+            PolynomialCoeffs::interpolate_coset(&evals, shift, None),
+            evals.clone().coset_ifft(shift)
+        );
+    }
+
+    #[test]
+    fn test_split_into() {
+        type F = GoldilocksField;
+        for (len, num_pieces) in [(0, 1), (16, 4), (17, 4), (5, 8), (100, 3)] {
+            let poly = PolynomialCoeffs::new(F::rand_vec(len));
+            let pieces = poly.split_into(num_pieces);
+            assert_eq!(pieces.len(), num_pieces);
+            let chunk_size = len.div_ceil(num_pieces);
+            for _ in 0..10 {
+                let x = F::rand();
+                let x_chunk = x.exp_u64(chunk_size as u64);
+                let recombined = pieces
+                    .iter()
+                    .rev()
+                    .fold(F::ZERO, |acc, piece| acc * x_chunk + piece.eval(x));
+                assert_eq!(recombined, poly.eval(x));
+            }
+        }
+    }
+
     #[test]
     fn test_polynomial_multiplication() {
         type F = GoldilocksField;
@@ -0,0 +1,169 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::polynomial::PolynomialCoeffs;
+use crate::types::Field;
+
+/// A polynomial in coefficient form which only stores its non-zero terms.
+///
+/// The terms are kept sorted by degree, with at most one term per degree and no zero coefficients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparsePolynomial<F: Field> {
+    /// The `(degree, coefficient)` pairs of the non-zero terms.
+    pub terms: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Creates a polynomial from arbitrary `(degree, coefficient)` pairs. Coefficients of equal
+    /// degree are summed, and zero terms are dropped.
+    pub fn new(terms: Vec<(usize, F)>) -> Self {
+        let terms = terms
+            .into_iter()
+            .sorted_by_key(|&(degree, _)| degree)
+            .coalesce(|(d0, c0), (d1, c1)| {
+                if d0 == d1 {
+                    Ok((d0, c0 + c1))
+                } else {
+                    Err(((d0, c0), (d1, c1)))
+                }
+            })
+            .filter(|(_, c)| c.is_nonzero())
+            .collect();
+        Self { terms }
+    }
+
+    pub fn zero() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Degree of the polynomial + 1, or 0 for the zero polynomial.
+    pub fn degree_plus_one(&self) -> usize {
+        self.terms.last().map_or(0, |&(degree, _)| degree + 1)
+    }
+
+    pub fn eval(&self, x: F) -> F {
+        // Walk up the degrees, multiplying in `x^(d_i - d_{i-1})` at each step.
+        let mut x_pow = F::ONE;
+        let mut prev_degree = 0;
+        let mut acc = F::ZERO;
+        for &(degree, c) in &self.terms {
+            x_pow *= x.exp_u64((degree - prev_degree) as u64);
+            prev_degree = degree;
+            acc += c * x_pow;
+        }
+        acc
+    }
+
+    /// Materializes the dense coefficients, trimmed to `degree_plus_one`.
+    pub fn to_dense(&self) -> PolynomialCoeffs<F> {
+        let mut coeffs = vec![F::ZERO; self.degree_plus_one()];
+        for &(degree, c) in &self.terms {
+            coeffs[degree] = c;
+        }
+        PolynomialCoeffs::new(coeffs)
+    }
+}
+
+impl<F: Field> From<&PolynomialCoeffs<F>> for SparsePolynomial<F> {
+    fn from(poly: &PolynomialCoeffs<F>) -> Self {
+        let terms = poly
+            .coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_nonzero())
+            .map(|(degree, &c)| (degree, c))
+            .collect();
+        Self { terms }
+    }
+}
+
+impl<F: Field> From<&SparsePolynomial<F>> for PolynomialCoeffs<F> {
+    fn from(poly: &SparsePolynomial<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: Field> Add for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let terms = self
+            .terms
+            .iter()
+            .merge_join_by(&rhs.terms, |(d0, _), (d1, _)| d0.cmp(d1))
+            .filter_map(|pair| match pair {
+                EitherOrBoth::Both(&(degree, c0), &(_, c1)) => {
+                    Some((degree, c0 + c1)).filter(|(_, c)| c.is_nonzero())
+                }
+                EitherOrBoth::Left(&term) | EitherOrBoth::Right(&term) => Some(term),
+            })
+            .collect();
+        SparsePolynomial { terms }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks_field::GoldilocksField;
+    use crate::types::Sample;
+
+    type F = GoldilocksField;
+
+    fn sparse_polys() -> Vec<SparsePolynomial<F>> {
+        vec![
+            SparsePolynomial::zero(),
+            SparsePolynomial::new(vec![(0, F::rand())]),
+            SparsePolynomial::new(vec![(1 << 10, F::ONE), (0, F::NEG_ONE)]),
+            SparsePolynomial::new(vec![(3, F::rand()), (17, F::rand()), (100, F::rand())]),
+        ]
+    }
+
+    #[test]
+    fn test_new_normalizes() {
+        let poly = SparsePolynomial::new(vec![
+            (5, F::ONE),
+            (2, F::TWO),
+            (5, F::NEG_ONE),
+            (0, F::ZERO),
+            (2, F::ONE),
+        ]);
+        assert_eq!(poly.terms, vec![(2, F::from_canonical_u64(3))]);
+    }
+
+    #[test]
+    fn test_eval_and_dense_conversion() {
+        for sparse in sparse_polys() {
+            let dense = sparse.to_dense();
+            assert_eq!(dense, dense.trimmed());
+            assert_eq!(SparsePolynomial::from(&dense), sparse);
+
+            let x = F::rand();
+            assert_eq!(sparse.eval(x), dense.eval(x));
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        let polys = sparse_polys();
+        for a in &polys {
+            for b in &polys {
+                let sum = a + b;
+                assert_eq!(sum.to_dense(), &a.to_dense() + &b.to_dense());
+                assert_eq!(sum, SparsePolynomial::from(&sum.to_dense()));
+            }
+        }
+
+        // Cancelling terms are dropped.
+        let a = SparsePolynomial::new(vec![(4, F::ONE), (9, F::TWO)]);
+        let b = SparsePolynomial::new(vec![(4, F::NEG_ONE)]);
+        assert_eq!((&a + &b).terms, vec![(9, F::TWO)]);
+    }
+}
@@ -222,6 +222,20 @@ pub trait Field:
         buf
     }
 
+    /// Like `batch_multiplicative_inverse`, but maps zeros to `None` instead of panicking. The
+    /// nonzero elements are inverted together with a single batch inversion.
+    fn try_batch_multiplicative_inverse(x: &[Self]) -> Vec<Option<Self>> {
+        let nonzero = x
+            .iter()
+            .copied()
+            .filter(|xi| xi.is_nonzero())
+            .collect::<Vec<_>>();
+        let mut inverses = Self::batch_multiplicative_inverse(&nonzero).into_iter();
+        x.iter()
+            .map(|xi| xi.is_nonzero().then(|| inverses.next().unwrap()))
+            .collect()
+    }
+
     /// Compute the inverse of 2^exp in this field.
     #[inline]
     fn inverse_2exp(exp: usize) -> Self {
@@ -426,10 +440,19 @@ pub trait Field:
         self.kth_root_u64(3)
     }
 
+    /// Returns the powers `1, self, self^2, ...`, computed incrementally. This is the sequence used
+    /// to apply a coset shift to coefficients, as in `coset_fft_with_options`.
     fn powers(&self) -> Powers<Self> {
         self.shifted_powers(Self::ONE)
     }
 
+    /// Returns the powers `self^start_exp, self^(start_exp + 1), ...`, computed incrementally.
+    fn powers_from(&self, start_exp: u64) -> Powers<Self> {
+        self.shifted_powers(self.exp_u64(start_exp))
+    }
+
+    /// Returns the powers of `self` scaled by `start`, i.e. `start, start * self, start * self^2, ...`,
+    /// computed incrementally.
     fn shifted_powers(&self, start: Self) -> Powers<Self> {
         Powers {
             base: *self,
@@ -618,7 +641,7 @@ impl<F: Field> Powers<F> {
 
 #[cfg(test)]
 mod tests {
-    use super::Field;
+    use super::{Field, Sample};
     use crate::goldilocks_field::GoldilocksField;
 
     #[test]
@@ -637,4 +660,62 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_powers_from() {
+        type F = GoldilocksField;
+
+        let x = F::rand();
+        for start_exp in [0, 1, 7, 1 << 40] {
+            for (i, power) in x.powers_from(start_exp).take(8).enumerate() {
+                assert_eq!(power, x.exp_u64(start_exp + i as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shifted_powers() {
+        type F = GoldilocksField;
+
+        let shift = F::rand();
+        let start = F::rand();
+        let len = 16;
+        assert_eq!(
+            shift.powers().take(len).collect::<Vec<_>>(),
+            (0..len)
+                .map(|i| shift.exp_u64(i as u64))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            shift.shifted_powers(start).take(len).collect::<Vec<_>>(),
+            (0..len)
+                .map(|i| start * shift.exp_u64(i as u64))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_try_batch_multiplicative_inverse() {
+        type F = GoldilocksField;
+
+        let x = [
+            F::ZERO,
+            F::rand(),
+            F::rand(),
+            F::ZERO,
+            F::ZERO,
+            F::rand(),
+            F::rand(),
+            F::rand(),
+            F::ZERO,
+        ];
+        let inverses = F::try_batch_multiplicative_inverse(&x);
+        assert_eq!(inverses.len(), x.len());
+        for (&xi, inverse) in x.iter().zip(inverses) {
+            assert_eq!(inverse, xi.try_inverse());
+        }
+
+        assert!(F::try_batch_multiplicative_inverse(&[]).is_empty());
+        assert_eq!(F::try_batch_multiplicative_inverse(&[F::ZERO]), vec![None]);
+    }
 }
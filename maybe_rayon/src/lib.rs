@@ -10,6 +10,7 @@ pub use rayon::{
         IndexedParallelIterator, ParallelDrainFull, ParallelDrainRange, ParallelExtend,
         ParallelIterator,
     },
+    scope, Scope,
 };
 #[cfg(feature = "parallel")]
 use rayon::{
@@ -24,6 +25,7 @@ use {
     alloc::vec::Vec,
     core::{
         iter::{FlatMap, IntoIterator, Iterator},
+        marker::PhantomData,
         slice::{self, Chunks, ChunksExact, ChunksExactMut, ChunksMut},
     },
 };
@@ -287,3 +289,57 @@ where
 {
     (oper_a(), oper_b())
 }
+
+/// Sequential stand-in for `rayon::Scope`: spawned closures run immediately, in order.
+#[cfg(not(feature = "parallel"))]
+pub struct Scope<'scope> {
+    marker: PhantomData<&'scope mut &'scope ()>,
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<'scope> Scope<'scope> {
+    pub fn spawn<BODY>(&self, body: BODY)
+    where
+        BODY: FnOnce(&Scope<'scope>) + Send + 'scope,
+    {
+        body(self)
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn scope<'scope, OP, R>(op: OP) -> R
+where
+    OP: FnOnce(&Scope<'scope>) -> R + Send,
+    R: Send,
+{
+    op(&Scope {
+        marker: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_scope() {
+        let counters: [AtomicUsize; 8] = Default::default();
+        let total = scope(|s| {
+            for (i, counter) in counters.iter().enumerate() {
+                s.spawn(move |s| {
+                    counter.fetch_add(i, Ordering::Relaxed);
+                    s.spawn(move |_| {
+                        counter.fetch_add(1, Ordering::Relaxed);
+                    });
+                });
+            }
+            counters.len()
+        });
+        assert_eq!(total, 8);
+        for (i, counter) in counters.iter().enumerate() {
+            assert_eq!(counter.load(Ordering::Relaxed), i + 1);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod allocator;
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use plonky2::field::cosets::CosetDomain;
 use plonky2::field::goldilocks_field::GoldilocksField;
 use plonky2::field::polynomial::PolynomialCoeffs;
 use plonky2::field::types::Field;
@@ -37,9 +38,35 @@ pub(crate) fn bench_ldes<F: Field>(c: &mut Criterion) {
     }
 }
 
+pub(crate) fn bench_coset_domain_ldes<F: Field>(c: &mut Criterion) {
+    const RATE_BITS: usize = 3;
+    const NUM_COLUMNS: usize = 32;
+
+    let mut group = c.benchmark_group(format!("coset_domain_lde<{}>", type_name::<F>()));
+
+    for size_log in [13, 14, 15, 16] {
+        let orig_size = 1 << (size_log - RATE_BITS);
+        let lde_size = 1 << size_log;
+        let domain = CosetDomain::<F>::new(size_log);
+
+        group.bench_with_input(BenchmarkId::from_parameter(lde_size), &lde_size, |b, _| {
+            let columns = (0..NUM_COLUMNS)
+                .map(|_| PolynomialCoeffs::new(F::rand_vec(orig_size)))
+                .collect::<Vec<_>>();
+            b.iter(|| {
+                columns
+                    .iter()
+                    .map(|coeffs| domain.lde(coeffs))
+                    .collect::<Vec<_>>()
+            });
+        });
+    }
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     bench_ffts::<GoldilocksField>(c);
     bench_ldes::<GoldilocksField>(c);
+    bench_coset_domain_ldes::<GoldilocksField>(c);
 }
 
 criterion_group!(benches, criterion_benchmark);
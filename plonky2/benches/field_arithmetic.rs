@@ -1,11 +1,12 @@
 mod allocator;
 
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use plonky2::field::batch_util::dot_product;
 use plonky2::field::extension::quadratic::QuadraticExtension;
 use plonky2::field::extension::quartic::QuarticExtension;
 use plonky2::field::extension::quintic::QuinticExtension;
 use plonky2::field::goldilocks_field::GoldilocksField;
-use plonky2::field::types::Field;
+use plonky2::field::types::{Field, Sample};
 use tynm::type_name;
 
 pub(crate) fn bench_field<F: Field>(c: &mut Criterion) {
@@ -171,7 +172,33 @@ pub(crate) fn bench_field<F: Field>(c: &mut Criterion) {
     );
 }
 
+fn bench_dot_product(c: &mut Criterion) {
+    type F = GoldilocksField;
+    const LEN: usize = 1 << 14;
+
+    let mut group = c.benchmark_group("dot_product");
+    group.bench_function("packed", |b| {
+        b.iter_batched(
+            || (F::rand_vec(LEN), F::rand_vec(LEN)),
+            |(x, y)| dot_product(&x, &y),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("scalar", |b| {
+        b.iter_batched(
+            || (F::rand_vec(LEN), F::rand_vec(LEN)),
+            |(x, y)| {
+                x.iter()
+                    .zip(&y)
+                    .fold(F::ZERO, |acc, (&x_i, &y_i)| acc + x_i * y_i)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
+    bench_dot_product(c);
     bench_field::<GoldilocksField>(c);
     bench_field::<QuadraticExtension<GoldilocksField>>(c);
     bench_field::<QuarticExtension<GoldilocksField>>(c);
@@ -0,0 +1,42 @@
+mod allocator;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use plonky2::field::goldilocks_field::GoldilocksField;
+use plonky2::field::packable::Packable;
+use plonky2::field::packed::PackedField;
+use plonky2::field::types::Sample;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    type F = GoldilocksField;
+    type P = <F as Packable>::Packing;
+
+    // A row-major execution trace of 2^16 rows of 50 columns.
+    const ROWS: usize = 1 << 16;
+    const COLS: usize = 50;
+    let trace = F::rand_vec(ROWS * COLS);
+
+    let mut group = c.benchmark_group("pack_trace_column");
+    group.bench_function("from_strided", |b| {
+        b.iter(|| {
+            (0..ROWS / P::WIDTH)
+                .map(|i| P::from_strided(&trace[i * P::WIDTH * COLS..], COLS))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("scalar_gather", |b| {
+        b.iter(|| {
+            (0..ROWS / P::WIDTH)
+                .map(|i| {
+                    let mut packed = P::ZEROS;
+                    for (j, x) in packed.as_slice_mut().iter_mut().enumerate() {
+                        *x = trace[(i * P::WIDTH + j) * COLS];
+                    }
+                    packed
+                })
+                .collect::<Vec<_>>()
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+mod allocator;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use plonky2::field::goldilocks_field::GoldilocksField;
+use plonky2::field::polynomial::PolynomialCoeffs;
+use plonky2::field::types::Field;
+use tynm::type_name;
+
+pub(crate) fn bench_divide_by_z_h<F: Field>(c: &mut Criterion) {
+    // A multiple of `X^n - 1` of degree just under 2^16.
+    const LOG_N: usize = 15;
+    let n = 1 << LOG_N;
+
+    let mut z_h = PolynomialCoeffs::zero(n + 1);
+    z_h.coeffs[0] = F::NEG_ONE;
+    z_h.coeffs[n] = F::ONE;
+    let poly = (&PolynomialCoeffs::new(F::rand_vec(n)) * &z_h).trimmed();
+
+    let mut group = c.benchmark_group(format!("divide_by_z_h<{}>", type_name::<F>()));
+    let degree = poly.len();
+    group.bench_with_input(BenchmarkId::new("div_rem", degree), &degree, |b, _| {
+        b.iter(|| poly.div_rem(&z_h));
+    });
+    group.bench_with_input(
+        BenchmarkId::new("divide_by_z_h", degree),
+        &degree,
+        |b, _| {
+            b.iter(|| poly.divide_by_z_h(n));
+        },
+    );
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_divide_by_z_h::<GoldilocksField>(c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+mod allocator;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use plonky2::field::goldilocks_field::GoldilocksField;
+use plonky2::field::polynomial::PolynomialCoeffs;
+use plonky2::field::types::Field;
+use tynm::type_name;
+
+pub(crate) fn bench_eval_many_at<F: Field>(c: &mut Criterion) {
+    const NUM_POLYS: usize = 100;
+    const LOG_N: usize = 12;
+    let n = 1 << LOG_N;
+
+    let polys = (0..NUM_POLYS)
+        .map(|_| PolynomialCoeffs::new(F::rand_vec(n)))
+        .collect::<Vec<_>>();
+    let poly_refs = polys.iter().collect::<Vec<_>>();
+    let x = F::rand();
+
+    let mut group = c.benchmark_group(format!("eval_many_at<{}>", type_name::<F>()));
+    group.bench_with_input(BenchmarkId::new("eval", n), &n, |b, _| {
+        b.iter(|| polys.iter().map(|p| p.eval(x)).collect::<Vec<_>>());
+    });
+    group.bench_with_input(BenchmarkId::new("eval_many_at", n), &n, |b, _| {
+        b.iter(|| PolynomialCoeffs::eval_many_at(&poly_refs, x));
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_eval_many_at::<GoldilocksField>(c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
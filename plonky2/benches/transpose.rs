@@ -3,7 +3,7 @@ mod allocator;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use plonky2::field::goldilocks_field::GoldilocksField;
 use plonky2::field::types::Sample;
-use plonky2::util::transpose;
+use plonky2::util::{transpose, transpose_rectangular};
 
 fn criterion_benchmark(c: &mut Criterion) {
     type F = GoldilocksField;
@@ -22,6 +22,17 @@ fn criterion_benchmark(c: &mut Criterion) {
             b.iter(|| transpose(&matrix));
         });
     }
+    drop(group);
+
+    // Execution traces are tall row-major matrices, e.g. 2^16 rows of 50 columns.
+    const ROWS: usize = 1 << 16;
+    const COLS: usize = 50;
+
+    let mut group = c.benchmark_group("transpose_rectangular");
+    let matrix = F::rand_vec(ROWS * COLS);
+    group.bench_function(BenchmarkId::from_parameter(COLS), |b| {
+        b.iter(|| transpose_rectangular(&matrix, ROWS, COLS));
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -93,7 +93,8 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
                         &polynomials[group_start..i + 1],
                         rate_bits,
                         blinding,
-                        fft_root_table[i]
+                        fft_root_table[i],
+                        None,
                     )
                 );
 
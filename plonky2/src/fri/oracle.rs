@@ -4,6 +4,7 @@ use alloc::{format, vec::Vec};
 use itertools::Itertools;
 use plonky2_field::types::Field;
 use plonky2_maybe_rayon::*;
+use rand::RngCore;
 
 use crate::field::extension::Extendable;
 use crate::field::fft::FftRootTable;
@@ -11,7 +12,7 @@ use crate::field::packed::PackedField;
 use crate::field::polynomial::{PolynomialCoeffs, PolynomialValues};
 use crate::fri::proof::FriProof;
 use crate::fri::prover::fri_proof;
-use crate::fri::structure::{FriBatchInfo, FriInstanceInfo};
+use crate::fri::structure::{FriBatchInfo, FriInstanceInfo, FriOracleInfo};
 use crate::fri::FriParams;
 use crate::hash::hash_types::RichField;
 use crate::hash::merkle_tree::MerkleTree;
@@ -61,6 +62,48 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
         cap_height: usize,
         timing: &mut TimingTree,
         fft_root_table: Option<&FftRootTable<F>>,
+    ) -> Self {
+        Self::from_values_helper(
+            values,
+            rate_bits,
+            blinding,
+            cap_height,
+            timing,
+            fft_root_table,
+            None,
+        )
+    }
+
+    /// Same as `from_values`, but draws the blinding salts from `rng`, so that the commitment is
+    /// reproducible for a given seed.
+    pub fn from_values_with_rng<R: RngCore>(
+        values: Vec<PolynomialValues<F>>,
+        rate_bits: usize,
+        blinding: bool,
+        cap_height: usize,
+        timing: &mut TimingTree,
+        fft_root_table: Option<&FftRootTable<F>>,
+        rng: &mut R,
+    ) -> Self {
+        Self::from_values_helper(
+            values,
+            rate_bits,
+            blinding,
+            cap_height,
+            timing,
+            fft_root_table,
+            Some(rng),
+        )
+    }
+
+    pub(crate) fn from_values_helper(
+        values: Vec<PolynomialValues<F>>,
+        rate_bits: usize,
+        blinding: bool,
+        cap_height: usize,
+        timing: &mut TimingTree,
+        fft_root_table: Option<&FftRootTable<F>>,
+        rng: Option<&mut dyn RngCore>,
     ) -> Self {
         let coeffs = timed!(
             timing,
@@ -68,13 +111,14 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
             values.into_par_iter().map(|v| v.ifft()).collect::<Vec<_>>()
         );
 
-        Self::from_coeffs(
+        Self::from_coeffs_helper(
             coeffs,
             rate_bits,
             blinding,
             cap_height,
             timing,
             fft_root_table,
+            rng,
         )
     }
 
@@ -86,12 +130,54 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
         cap_height: usize,
         timing: &mut TimingTree,
         fft_root_table: Option<&FftRootTable<F>>,
+    ) -> Self {
+        Self::from_coeffs_helper(
+            polynomials,
+            rate_bits,
+            blinding,
+            cap_height,
+            timing,
+            fft_root_table,
+            None,
+        )
+    }
+
+    /// Same as `from_coeffs`, but draws the blinding salts from `rng`, so that the commitment is
+    /// reproducible for a given seed.
+    pub fn from_coeffs_with_rng<R: RngCore>(
+        polynomials: Vec<PolynomialCoeffs<F>>,
+        rate_bits: usize,
+        blinding: bool,
+        cap_height: usize,
+        timing: &mut TimingTree,
+        fft_root_table: Option<&FftRootTable<F>>,
+        rng: &mut R,
+    ) -> Self {
+        Self::from_coeffs_helper(
+            polynomials,
+            rate_bits,
+            blinding,
+            cap_height,
+            timing,
+            fft_root_table,
+            Some(rng),
+        )
+    }
+
+    pub(crate) fn from_coeffs_helper(
+        polynomials: Vec<PolynomialCoeffs<F>>,
+        rate_bits: usize,
+        blinding: bool,
+        cap_height: usize,
+        timing: &mut TimingTree,
+        fft_root_table: Option<&FftRootTable<F>>,
+        rng: Option<&mut dyn RngCore>,
     ) -> Self {
         let degree = polynomials[0].len();
         let lde_values = timed!(
             timing,
             "FFT + blinding",
-            Self::lde_values(&polynomials, rate_bits, blinding, fft_root_table)
+            Self::lde_values(&polynomials, rate_bits, blinding, fft_root_table, rng)
         );
 
         let mut leaves = timed!(timing, "transpose LDEs", transpose(&lde_values));
@@ -111,16 +197,29 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
         }
     }
 
+    /// Computes the LDEs of `polynomials`. If blinding, salts are drawn from `rng` if given, and
+    /// from the OS otherwise.
     pub(crate) fn lde_values(
         polynomials: &[PolynomialCoeffs<F>],
         rate_bits: usize,
         blinding: bool,
         fft_root_table: Option<&FftRootTable<F>>,
+        rng: Option<&mut dyn RngCore>,
     ) -> Vec<Vec<F>> {
         let degree = polynomials[0].len();
 
         // If blinding, salt with two random elements to each leaf vector.
         let salt_size = if blinding { SALT_SIZE } else { 0 };
+        let lde_size = degree << rate_bits;
+        let salts: Vec<Vec<F>> = match rng {
+            Some(rng) => (0..salt_size)
+                .map(|_| (0..lde_size).map(|_| F::sample(rng)).collect())
+                .collect(),
+            None => (0..salt_size)
+                .into_par_iter()
+                .map(|_| F::rand_vec(lde_size))
+                .collect(),
+        };
 
         polynomials
             .par_iter()
@@ -130,11 +229,7 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
                     .coset_fft_with_options(F::coset_shift(), Some(rate_bits), fft_root_table)
                     .values
             })
-            .chain(
-                (0..salt_size)
-                    .into_par_iter()
-                    .map(|_| F::rand_vec(degree << rate_bits)),
-            )
+            .chain(salts.into_par_iter())
             .collect()
     }
 
@@ -235,4 +330,144 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
 
         fri_proof
     }
+
+    /// Opens every polynomial in the batch at each of `points`, for use as a standalone polynomial
+    /// commitment scheme. Returns the opened values, indexed by point then by polynomial, along
+    /// with a FRI proof of their correctness. The values are observed by `challenger` before the
+    /// FRI proof is generated. They can be checked with
+    /// [`verify_fri_proof`](crate::fri::verifier::verify_fri_proof) using the instance
+    /// [`FriInstanceInfo::single_oracle`].
+    #[allow(clippy::type_complexity)]
+    pub fn open_at_points(
+        &self,
+        points: &[F::Extension],
+        challenger: &mut Challenger<F, C::Hasher>,
+        fri_params: &FriParams,
+        timing: &mut TimingTree,
+    ) -> (Vec<Vec<F::Extension>>, FriProof<F, C::Hasher, D>) {
+        let openings = points
+            .iter()
+            .map(|&point| {
+                self.polynomials
+                    .par_iter()
+                    .map(|p| p.to_extension::<D>().eval(point))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for values in &openings {
+            challenger.observe_extension_elements::<D>(values);
+        }
+
+        let instance = FriInstanceInfo::single_oracle(
+            FriOracleInfo {
+                num_polys: self.polynomials.len(),
+                blinding: self.blinding,
+            },
+            points,
+        );
+        let proof = Self::prove_openings(
+            &instance,
+            &[self],
+            challenger,
+            fri_params,
+            None,
+            None,
+            timing,
+        );
+
+        (openings, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::types::Sample;
+    use crate::fri::reduction_strategies::FriReductionStrategy;
+    use crate::fri::structure::{FriOpeningBatch, FriOpenings};
+    use crate::fri::verifier::verify_fri_proof;
+    use crate::fri::FriConfig;
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+
+    #[test]
+    fn test_open_at_points() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type FF = <F as Extendable<D>>::Extension;
+
+        let degree_bits = 6;
+        let fri_config = FriConfig {
+            rate_bits: 2,
+            cap_height: 1,
+            proof_of_work_bits: 0,
+            reduction_strategy: FriReductionStrategy::ConstantArityBits(2, 2),
+            num_query_rounds: 10,
+        };
+        let fri_params = fri_config.fri_params(degree_bits, false);
+
+        let polys = (0..3)
+            .map(|_| PolynomialCoeffs::new(F::rand_vec(1 << degree_bits)))
+            .collect::<Vec<_>>();
+        let mut timing = TimingTree::default();
+        let batch = PolynomialBatch::<F, C, D>::from_coeffs(
+            polys.clone(),
+            fri_config.rate_bits,
+            false,
+            fri_config.cap_height,
+            &mut timing,
+            None,
+        );
+
+        let points = [FF::rand(), FF::rand()];
+        let mut challenger = Challenger::<F, <C as GenericConfig<D>>::Hasher>::new();
+        challenger.observe_cap(&batch.merkle_tree.cap);
+        let (openings, proof) =
+            batch.open_at_points(&points, &mut challenger, &fri_params, &mut timing);
+
+        for (point, values) in points.iter().zip(&openings) {
+            for (poly, &value) in polys.iter().zip(values) {
+                assert_eq!(poly.to_extension::<D>().eval(*point), value);
+            }
+        }
+
+        // Verify as a standalone commitment scheme.
+        let mut verifier_challenger = Challenger::<F, <C as GenericConfig<D>>::Hasher>::new();
+        verifier_challenger.observe_cap(&batch.merkle_tree.cap);
+        for values in &openings {
+            verifier_challenger.observe_extension_elements::<D>(values);
+        }
+        let challenges = verifier_challenger.fri_challenges::<C, D>(
+            &proof.commit_phase_merkle_caps,
+            &proof.final_poly,
+            proof.pow_witness,
+            degree_bits,
+            &fri_config,
+            None,
+            None,
+        );
+        let instance = FriInstanceInfo::single_oracle(
+            FriOracleInfo {
+                num_polys: polys.len(),
+                blinding: false,
+            },
+            &points,
+        );
+        let fri_openings = FriOpenings {
+            batches: openings
+                .into_iter()
+                .map(|values| FriOpeningBatch { values })
+                .collect(),
+        };
+        verify_fri_proof::<F, C, D>(
+            &instance,
+            &fri_openings,
+            &challenges,
+            core::slice::from_ref(&batch.merkle_tree.cap),
+            &proof,
+            &fri_params,
+        )
+    }
 }
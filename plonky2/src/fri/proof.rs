@@ -1,12 +1,14 @@
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use itertools::izip;
 use serde::{Deserialize, Serialize};
 
 use crate::field::extension::{flatten, unflatten, Extendable};
 use crate::field::polynomial::PolynomialCoeffs;
+use crate::fri::structure::{FriInstanceInfo, FriOpenings};
+use crate::fri::verifier::{compute_evaluation, fri_combine_initial, PrecomputedReducedOpenings};
 use crate::fri::FriParams;
 use crate::gadgets::polynomial::PolynomialCoeffsExtTarget;
 use crate::hash::hash_types::{MerkleCapTarget, RichField};
@@ -15,9 +17,10 @@ use crate::hash::merkle_tree::MerkleCap;
 use crate::hash::path_compression::{compress_merkle_proofs, decompress_merkle_proofs};
 use crate::iop::ext_target::ExtensionTarget;
 use crate::iop::target::Target;
-use crate::plonk::config::Hasher;
+use crate::plonk::config::{GenericConfig, Hasher};
 use crate::plonk::plonk_common::salt_size;
-use crate::plonk::proof::{FriInferredElements, ProofChallenges};
+use crate::plonk::proof::FriInferredElements;
+use crate::util::reverse_bits;
 
 /// Evaluations and Merkle proof produced by the prover in a FRI query step.
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -237,9 +240,9 @@ impl<F: RichField + Extendable<D>, H: Hasher<F>, const D: usize> FriProof<F, H,
 
 impl<F: RichField + Extendable<D>, H: Hasher<F>, const D: usize> CompressedFriProof<F, H, D> {
     /// Decompress all the Merkle paths in the FRI proof and reinsert duplicate indices.
-    pub(crate) fn decompress(
+    pub fn decompress(
         self,
-        challenges: &ProofChallenges<F, D>,
+        challenges: &FriChallenges<F, D>,
         fri_inferred_elements: FriInferredElements<F, D>,
         params: &FriParams,
     ) -> FriProof<F, H, D> {
@@ -253,7 +256,7 @@ impl<F: RichField + Extendable<D>, H: Hasher<F>, const D: usize> CompressedFriPr
         let FriChallenges {
             fri_query_indices: indices,
             ..
-        } = &challenges.fri_challenges;
+        } = challenges;
         let mut fri_inferred_elements = fri_inferred_elements.0.into_iter();
         let cap_height = params.config.cap_height;
         let reduction_arity_bits = &params.reduction_arity_bits;
@@ -358,6 +361,64 @@ impl<F: RichField + Extendable<D>, H: Hasher<F>, const D: usize> CompressedFriPr
             pow_witness,
         }
     }
+
+    /// Computes all coset elements that can be inferred in the FRI reduction steps.
+    pub fn get_inferred_elements<C: GenericConfig<D, F = F, Hasher = H>>(
+        &self,
+        instance: &FriInstanceInfo<F, D>,
+        openings: &FriOpenings<F, D>,
+        challenges: &FriChallenges<F, D>,
+        params: &FriParams,
+    ) -> FriInferredElements<F, D> {
+        let FriChallenges {
+            fri_alpha,
+            fri_betas,
+            fri_query_indices,
+            ..
+        } = challenges;
+        let mut fri_inferred_elements = Vec::new();
+        // Holds the indices that have already been seen at each reduction depth.
+        let mut seen_indices_by_depth = vec![HashSet::new(); params.reduction_arity_bits.len()];
+        let precomputed_reduced_evals =
+            PrecomputedReducedOpenings::from_os_and_alpha(openings, *fri_alpha);
+        let log_n = params.degree_bits + params.config.rate_bits;
+        // Simulate the proof verification and collect the inferred elements.
+        // The content of the loop is basically the same as the `fri_verifier_query_round` function.
+        for &(mut x_index) in fri_query_indices {
+            let mut subgroup_x = F::MULTIPLICATIVE_GROUP_GENERATOR
+                * F::primitive_root_of_unity(log_n).exp_u64(reverse_bits(x_index, log_n) as u64);
+            let mut old_eval = fri_combine_initial::<F, C, D>(
+                instance,
+                &self.query_round_proofs.initial_trees_proofs[&x_index],
+                *fri_alpha,
+                subgroup_x,
+                &precomputed_reduced_evals,
+                params,
+            );
+            for (i, &arity_bits) in params.reduction_arity_bits.iter().enumerate() {
+                let coset_index = x_index >> arity_bits;
+                if !seen_indices_by_depth[i].insert(coset_index) {
+                    // If this index has already been seen, we can skip the rest of the reductions.
+                    break;
+                }
+                fri_inferred_elements.push(old_eval);
+                let arity = 1 << arity_bits;
+                let mut evals = self.query_round_proofs.steps[i][&coset_index].evals.clone();
+                let x_index_within_coset = x_index & (arity - 1);
+                evals.insert(x_index_within_coset, old_eval);
+                old_eval = compute_evaluation(
+                    subgroup_x,
+                    x_index_within_coset,
+                    arity_bits,
+                    &evals,
+                    fri_betas[i],
+                );
+                subgroup_x = subgroup_x.exp_power_of_2(arity_bits);
+                x_index = coset_index;
+            }
+        }
+        FriInferredElements(fri_inferred_elements)
+    }
 }
 
 #[derive(Debug)]
@@ -2,7 +2,7 @@
 //! involved, and the points they are opened at.
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::ops::Range;
 
 use crate::field::extension::Extendable;
@@ -18,6 +18,23 @@ pub struct FriInstanceInfo<F: RichField + Extendable<D>, const D: usize> {
     pub batches: Vec<FriBatchInfo<F, D>>,
 }
 
+impl<F: RichField + Extendable<D>, const D: usize> FriInstanceInfo<F, D> {
+    /// An instance in which every polynomial of a single oracle is opened at each of `points`.
+    pub fn single_oracle(oracle: FriOracleInfo, points: &[F::Extension]) -> Self {
+        let batches = points
+            .iter()
+            .map(|&point| FriBatchInfo {
+                point,
+                polynomials: FriPolynomialInfo::from_range(0, 0..oracle.num_polys),
+            })
+            .collect();
+        Self {
+            oracles: vec![oracle],
+            batches,
+        }
+    }
+}
+
 /// Describes an instance of a FRI-based batch opening.
 #[derive(Debug)]
 pub struct FriInstanceInfoTarget<const D: usize> {
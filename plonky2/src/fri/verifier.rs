@@ -1,5 +1,6 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
 
 use anyhow::{ensure, Result};
 
@@ -46,6 +47,32 @@ pub(crate) fn compute_evaluation<F: Field + Extendable<D>, const D: usize>(
     interpolate(&points, beta, &barycentric_weights)
 }
 
+/// Specific causes of a FRI verification failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FriError {
+    /// The final polynomial has more coefficients than the FRI parameters allow.
+    FinalPolyTooLarge { got: usize, expected: usize },
+    /// An opening of the initial oracle at `oracle_index` is not consistent with its Merkle cap.
+    InitialMerkleProof { oracle_index: usize },
+}
+
+impl Display for FriError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FinalPolyTooLarge { got, expected } => write!(
+                f,
+                "Final polynomial has {got} coefficients, but at most {expected} are allowed."
+            ),
+            Self::InitialMerkleProof { oracle_index } => write!(
+                f,
+                "Opening of initial oracle {oracle_index} does not match its Merkle cap."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FriError {}
+
 pub(crate) fn fri_verify_proof_of_work<F: RichField + Extendable<D>, const D: usize>(
     fri_pow_response: F,
     config: &FriConfig,
@@ -71,6 +98,13 @@ pub fn verify_fri_proof<
     proof: &FriProof<F, C::Hasher, D>,
     params: &FriParams,
 ) -> Result<()> {
+    ensure!(
+        proof.final_poly.len() <= params.final_poly_len(),
+        FriError::FinalPolyTooLarge {
+            got: proof.final_poly.len(),
+            expected: params.final_poly_len(),
+        }
+    );
     validate_fri_proof_shape::<F, C, D>(proof, instance, params)?;
 
     // Size of the LDE domain.
@@ -113,8 +147,14 @@ fn fri_verify_initial_proof<F: RichField, H: Hasher<F>>(
     proof: &FriInitialTreeProof<F, H>,
     initial_merkle_caps: &[MerkleCap<F, H>],
 ) -> Result<()> {
-    for ((evals, merkle_proof), cap) in proof.evals_proofs.iter().zip(initial_merkle_caps) {
-        verify_merkle_proof_to_cap::<F, H>(evals.clone(), x_index, cap, merkle_proof)?;
+    for (oracle_index, ((evals, merkle_proof), cap)) in proof
+        .evals_proofs
+        .iter()
+        .zip(initial_merkle_caps)
+        .enumerate()
+    {
+        verify_merkle_proof_to_cap::<F, H>(evals.clone(), x_index, cap, merkle_proof)
+            .map_err(|e| e.context(FriError::InitialMerkleProof { oracle_index }))?;
     }
 
     Ok(())
@@ -259,3 +299,47 @@ impl<F: RichField + Extendable<D>, const D: usize> PrecomputedReducedOpenings<F,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
+    use crate::plonk::circuit_builder::CircuitBuilder;
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+
+    #[test]
+    fn test_final_poly_too_large() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let x = builder.add_virtual_public_input();
+        let y = builder.square(x);
+        builder.register_public_input(y);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(x, F::TWO)?;
+        let mut proof = data.prove(pw)?;
+        let expected = proof.proof.opening_proof.final_poly.len();
+        proof
+            .proof
+            .opening_proof
+            .final_poly
+            .coeffs
+            .push(<F as Extendable<D>>::Extension::ONE);
+
+        let err = data.verify(proof).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<FriError>(),
+            Some(&FriError::FinalPolyTooLarge {
+                got: expected + 1,
+                expected,
+            })
+        );
+        Ok(())
+    }
+}
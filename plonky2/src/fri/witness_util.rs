@@ -9,11 +9,43 @@ use crate::iop::witness::WitnessWrite;
 use crate::plonk::config::AlgebraicHasher;
 
 /// Set the targets in a `FriProofTarget` to their corresponding values in a `FriProof`.
+///
+/// Returns an error if the proof doesn't have exactly as many query rounds as the target.
 pub fn set_fri_proof_target<F, W, H, const D: usize>(
     witness: &mut W,
     fri_proof_target: &FriProofTarget<D>,
     fri_proof: &FriProof<F, H, D>,
 ) -> Result<()>
+where
+    F: RichField + Extendable<D>,
+    W: WitnessWrite<F> + ?Sized,
+    H: AlgebraicHasher<F>,
+{
+    set_fri_proof_target_helper(witness, fri_proof_target, fri_proof, false)
+}
+
+/// Same as `set_fri_proof_target`, but the proof may contain more query rounds than the target,
+/// in which case only the first rounds are set and the others are ignored. The in-circuit
+/// verification then only has the security level of the target's config.
+pub fn set_fri_proof_target_allowing_extra_query_rounds<F, W, H, const D: usize>(
+    witness: &mut W,
+    fri_proof_target: &FriProofTarget<D>,
+    fri_proof: &FriProof<F, H, D>,
+) -> Result<()>
+where
+    F: RichField + Extendable<D>,
+    W: WitnessWrite<F> + ?Sized,
+    H: AlgebraicHasher<F>,
+{
+    set_fri_proof_target_helper(witness, fri_proof_target, fri_proof, true)
+}
+
+fn set_fri_proof_target_helper<F, W, H, const D: usize>(
+    witness: &mut W,
+    fri_proof_target: &FriProofTarget<D>,
+    fri_proof: &FriProof<F, H, D>,
+    allow_extra_query_rounds: bool,
+) -> Result<()>
 where
     F: RichField + Extendable<D>,
     W: WitnessWrite<F> + ?Sized,
@@ -64,10 +96,21 @@ where
         }
     }
 
+    let num_target_rounds = fri_proof_target.query_round_proofs.len();
+    let num_proof_rounds = fri_proof.query_round_proofs.len();
+    if num_proof_rounds < num_target_rounds
+        || (num_proof_rounds > num_target_rounds && !allow_extra_query_rounds)
+    {
+        return Err(anyhow!(
+            "fri_proof->query_round_proofs' length ({num_proof_rounds}) doesn't match the target length ({num_target_rounds})"
+        ));
+    }
+
+    // If allowed, any query rounds beyond those of the target are ignored.
     for (qt, q) in fri_proof_target
         .query_round_proofs
         .iter()
-        .zip_eq(&fri_proof.query_round_proofs)
+        .zip(&fri_proof.query_round_proofs)
     {
         for (at, a) in qt
             .initial_trees_proof
@@ -20,6 +20,26 @@ use crate::plonk::circuit_builder::CircuitBuilder;
 use crate::plonk::circuit_data::CommonCircuitData;
 use crate::util::serialization::{Buffer, IoResult, Read, Write};
 
+/// Shortest addition chains for the exponents `1..=16`, used by `CircuitBuilder::pow_const`.
+const SHORTEST_ADDITION_CHAINS: [&[u64]; 16] = [
+    &[1],
+    &[1, 2],
+    &[1, 2, 3],
+    &[1, 2, 4],
+    &[1, 2, 4, 5],
+    &[1, 2, 3, 6],
+    &[1, 2, 4, 6, 7],
+    &[1, 2, 4, 8],
+    &[1, 2, 4, 8, 9],
+    &[1, 2, 4, 5, 10],
+    &[1, 2, 4, 5, 10, 11],
+    &[1, 2, 4, 8, 12],
+    &[1, 2, 4, 8, 12, 13],
+    &[1, 2, 4, 6, 12, 14],
+    &[1, 2, 4, 5, 10, 15],
+    &[1, 2, 4, 8, 16],
+];
+
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
     /// Computes `-x`.
     pub fn neg(&mut self, x: Target) -> Target {
@@ -322,6 +342,47 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         self.exp_from_bits(base, exp_bits)
     }
 
+    /// Exponentiates `base` to the constant power `exponent`. Exponents up to 16 use a shortest
+    /// addition chain, larger ones use square-and-multiply, falling back to `exp_u64` when that
+    /// would need more multiplications than fit in a single arithmetic gate.
+    pub fn pow_const(&mut self, base: Target, exponent: u64) -> Target {
+        if exponent == 0 {
+            return self.one();
+        }
+
+        // Compare as `u64` before indexing, so that large exponents can't be truncated into the table.
+        if exponent <= SHORTEST_ADDITION_CHAINS.len() as u64 {
+            let chain = SHORTEST_ADDITION_CHAINS[exponent as usize - 1];
+            let mut powers = vec![base];
+            for (k, &c) in chain.iter().enumerate().skip(1) {
+                // Find two earlier chain elements summing to `c`.
+                let (i, j) = (0..k)
+                    .flat_map(|i| (i..k).map(move |j| (i, j)))
+                    .find(|&(i, j)| chain[i] + chain[j] == c)
+                    .expect("Invalid addition chain");
+                let power = self.mul(powers[i], powers[j]);
+                powers.push(power);
+            }
+            return *powers.last().unwrap();
+        }
+
+        let num_bits = 64 - exponent.leading_zeros() as usize;
+        let num_muls = num_bits - 1 + exponent.count_ones() as usize - 1;
+        if num_muls > self.num_base_arithmetic_ops_per_gate() {
+            // Cheaper to just use `ExponentiateGate`.
+            return self.exp_u64(base, exponent);
+        }
+
+        let mut product = base;
+        for i in (0..num_bits - 1).rev() {
+            product = self.square(product);
+            if (exponent >> i) & 1 == 1 {
+                product = self.mul(product, base);
+            }
+        }
+        product
+    }
+
     /// Computes `x / y`. Results in an unsatisfiable instance if `y = 0`.
     pub fn div(&mut self, x: Target, y: Target) -> Target {
         let x = self.convert_to_ext(x);
@@ -438,3 +499,120 @@ pub(crate) struct BaseArithmeticOperation<F: Field64> {
     multiplicand_1: Target,
     addend: Target,
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::types::{Field, Sample};
+    use crate::iop::witness::PartialWitness;
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    #[test]
+    fn test_pow_const() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::<F>::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let x = F::rand();
+        let xt = builder.add_virtual_target();
+        pw.set_target(xt, x)?;
+        for exponent in (0..=16).chain([17, 100, (1 << 32) + 5, u64::MAX]) {
+            let result = builder.pow_const(xt, exponent);
+            let expected = builder.constant(x.exp_u64(exponent));
+            builder.connect(result, expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+
+    #[test]
+    fn test_pow_const_gate_count() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+        let x = builder.add_virtual_target();
+        for exponent in 2..=16 {
+            builder.pow_const(x, exponent);
+        }
+        let pow_const_gates = builder.num_gates();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let x = builder.add_virtual_target();
+        for exponent in 2..=16 {
+            builder.exp_u64(x, exponent);
+        }
+        let exp_gates = builder.num_gates();
+
+        assert!(pow_const_gates < exp_gates);
+    }
+
+    #[test]
+    fn test_exp_from_bits_const_base() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::<F>::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let base = F::MULTIPLICATIVE_GROUP_GENERATOR;
+        // Short exponents use arithmetic gates, long ones fall back to `ExponentiationGate`.
+        for num_bits in [0, 1, 8, 20, 32] {
+            let exponent = 0x9e37_79b9_7f4a_7c15 & ((1u64 << num_bits) - 1);
+            let bits = (0..num_bits)
+                .map(|_| builder.add_virtual_bool_target_safe())
+                .collect::<Vec<_>>();
+            for (i, &bit) in bits.iter().enumerate() {
+                pw.set_bool_target(bit, (exponent >> i) & 1 == 1)?;
+            }
+            let result = builder.exp_from_bits_const_base(base, &bits);
+            let expected = builder.constant(base.exp_u64(exponent));
+            builder.connect(result, expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+
+    #[test]
+    fn test_exp_from_bits_const_base_gate_count() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let base = F::MULTIPLICATIVE_GROUP_GENERATOR;
+        let num_gates = |const_base: bool| {
+            let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+            for _ in 0..10 {
+                let bits = (0..8)
+                    .map(|_| builder.add_virtual_bool_target_unsafe())
+                    .collect::<Vec<_>>();
+                if const_base {
+                    builder.exp_from_bits_const_base(base, &bits);
+                } else {
+                    let base_t = builder.constant(base);
+                    builder.exp_from_bits(base_t, &bits);
+                }
+            }
+            builder.num_gates()
+        };
+
+        assert!(num_gates(true) < num_gates(false));
+    }
+}
@@ -193,6 +193,21 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         acc
     }
 
+    /// Returns `sum_i a_i * b_i`, using one arithmetic operation per term.
+    pub fn inner_product_ext(
+        &mut self,
+        a: &[ExtensionTarget<D>],
+        b: &[ExtensionTarget<D>],
+    ) -> ExtensionTarget<D> {
+        assert_eq!(a.len(), b.len());
+        let zero = self.zero_extension();
+        self.inner_product_extension(
+            F::ONE,
+            zero,
+            a.iter().copied().zip(b.iter().copied()).collect(),
+        )
+    }
+
     pub fn add_extension(
         &mut self,
         a: ExtensionTarget<D>,
@@ -627,6 +642,49 @@ mod tests {
     use crate::plonk::config::{GenericConfig, KeccakGoldilocksConfig, PoseidonGoldilocksConfig};
     use crate::plonk::verifier::verify;
 
+    #[test]
+    fn test_inner_product_ext() -> Result<()> {
+        const D: usize = 2;
+        const N: usize = 16;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type FF = <C as GenericConfig<D>>::FE;
+
+        let config = CircuitConfig::standard_recursion_config();
+
+        // Chained `mul_add_extension`, for comparison.
+        let mut chained_builder = CircuitBuilder::<F, D>::new(config.clone());
+        let a = chained_builder.add_virtual_extension_targets(N);
+        let b = chained_builder.add_virtual_extension_targets(N);
+        let mut acc = chained_builder.mul_extension(a[0], b[0]);
+        for i in 1..N {
+            acc = chained_builder.mul_add_extension(a[i], b[i], acc);
+        }
+        let chained_gates = chained_builder.num_gates();
+
+        let mut pw = PartialWitness::<F>::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let a_vals = FF::rand_vec(N);
+        let b_vals = FF::rand_vec(N);
+        let a = builder.add_virtual_extension_targets(N);
+        let b = builder.add_virtual_extension_targets(N);
+        for i in 0..N {
+            pw.set_extension_target(a[i], a_vals[i])?;
+            pw.set_extension_target(b[i], b_vals[i])?;
+        }
+        let inner_product = builder.inner_product_ext(&a, &b);
+        assert!(builder.num_gates() <= chained_gates);
+
+        let expected = builder
+            .constant_extension(a_vals.iter().zip(&b_vals).map(|(&x, &y)| x * y).sum::<FF>());
+        builder.connect_extension(inner_product, expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
     #[test]
     fn test_mul_many() -> Result<()> {
         const D: usize = 2;
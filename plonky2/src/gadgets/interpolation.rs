@@ -2,6 +2,7 @@
 use alloc::vec;
 
 use plonky2_field::extension::Extendable;
+use plonky2_util::log2_strict;
 
 use crate::gates::coset_interpolation::CosetInterpolationGate;
 use crate::hash::hash_types::RichField;
@@ -35,6 +36,41 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
 
         eval
     }
+
+    /// Interpolates the polynomial whose values on the coset `coset_start * <subgroup_gen>` are
+    /// `values`, and returns its evaluation at `eval_point`. Unlike `interpolate_coset`, the coset
+    /// may be shifted by an extension element. Uses the barycentric formula, with three arithmetic
+    /// operations per value. Results in an unsatisfiable instance if `eval_point` is in the coset.
+    pub fn interpolate_coset_ext(
+        &mut self,
+        values: &[ExtensionTarget<D>],
+        coset_start: ExtensionTarget<D>,
+        subgroup_gen: F,
+        eval_point: ExtensionTarget<D>,
+    ) -> ExtensionTarget<D> {
+        let n = values.len();
+        let subgroup_bits = log2_strict(n);
+        debug_assert_eq!(subgroup_gen.exp_power_of_2(subgroup_bits), F::ONE);
+
+        // Interpolating over `coset_start * H` at `eval_point` is the same as interpolating over
+        // `H` at `y = eval_point / coset_start`, which is `(y^n - 1) / n * sum_i v_i g^i / (y - g^i)`.
+        let y = self.div_extension(eval_point, coset_start);
+        let one = self.one_extension();
+        let zero = self.zero_extension();
+        let sum = subgroup_gen
+            .inverse()
+            .powers()
+            .zip(values)
+            .fold(zero, |acc, (g_inv_i, &v)| {
+                // `g^i / (y - g^i) = 1 / (g^{-i} y - 1)`.
+                let denominator = self.arithmetic_extension(g_inv_i, F::NEG_ONE, y, one, one);
+                self.div_add_extension(v, denominator, acc)
+            });
+
+        let y_n = self.exp_power_of_2_extension(y, subgroup_bits);
+        let n_inv = F::from_canonical_usize(n).inverse();
+        self.arithmetic_extension(n_inv, -n_inv, y_n, sum, sum)
+    }
 }
 
 #[cfg(test)]
@@ -111,4 +147,45 @@ mod tests {
 
         verify(proof, &data.verifier_only, &data.common)
     }
+
+    #[test]
+    fn test_interpolate_coset_ext() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type FF = <C as GenericConfig<D>>::FE;
+        let config = CircuitConfig::standard_recursion_config();
+        let pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        for subgroup_bits in 0..=4 {
+            let len = 1 << subgroup_bits;
+            let coset_start = FF::rand();
+            let g = F::primitive_root_of_unity(subgroup_bits);
+            let points = FF::cyclic_subgroup_coset_known_order(
+                <FF as FieldExtension<D>>::from_basefield(g),
+                coset_start,
+                len,
+            );
+            let values = FF::rand_vec(len);
+            let z = FF::rand();
+            let true_eval =
+                interpolant(&points.into_iter().zip(values.clone()).collect::<Vec<_>>()).eval(z);
+
+            let value_targets = values
+                .iter()
+                .map(|&v| builder.constant_extension(v))
+                .collect::<Vec<_>>();
+            let coset_start_target = builder.constant_extension(coset_start);
+            let zt = builder.constant_extension(z);
+            let eval = builder.interpolate_coset_ext(&value_targets, coset_start_target, g, zt);
+            let true_eval_target = builder.constant_extension(true_eval);
+            builder.connect_extension(eval, true_eval_target);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
 }
@@ -1,13 +1,46 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-use crate::field::extension::Extendable;
+use crate::field::extension::{Extendable, FieldExtension};
 use crate::hash::hash_types::RichField;
 use crate::iop::ext_target::{ExtensionAlgebraTarget, ExtensionTarget};
 use crate::iop::target::Target;
 use crate::plonk::circuit_builder::CircuitBuilder;
 use crate::util::reducing::ReducingFactorTarget;
 
+impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
+    /// Evaluates the fixed polynomial with coefficients `coeffs` at `x`, using Horner's method
+    /// with one arithmetic operation per coefficient.
+    pub fn constant_poly_eval(&mut self, coeffs: &[F], x: Target) -> Target {
+        let Some((&last, rest)) = coeffs.split_last() else {
+            return self.zero();
+        };
+        let one = self.one();
+        let mut acc = self.constant(last);
+        for &c in rest.iter().rev() {
+            acc = self.arithmetic(F::ONE, c, acc, x, one);
+        }
+        acc
+    }
+
+    /// Like `constant_poly_eval`, but evaluates the polynomial at an extension point.
+    pub fn constant_poly_eval_ext(
+        &mut self,
+        coeffs: &[F],
+        x: ExtensionTarget<D>,
+    ) -> ExtensionTarget<D> {
+        let Some((&last, rest)) = coeffs.split_last() else {
+            return self.zero_extension();
+        };
+        let one = self.one_extension();
+        let mut acc = self.constant_extension(F::Extension::from_basefield(last));
+        for &c in rest.iter().rev() {
+            acc = self.arithmetic_extension(F::ONE, c, acc, x, one);
+        }
+        acc
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PolynomialCoeffsExtTarget<const D: usize>(pub Vec<ExtensionTarget<D>>);
 
@@ -91,3 +124,51 @@ impl<const D: usize> PolynomialCoeffsExtAlgebraTarget<D> {
             .fold(acc, |acc, (&x, &c)| builder.mul_add_ext_algebra(c, x, acc))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::polynomial::PolynomialCoeffs;
+    use crate::field::types::Sample;
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    #[test]
+    fn test_constant_poly_eval() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type FF = <F as Extendable<D>>::Extension;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::<F>::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let x = F::rand();
+        let x_ext = FF::rand();
+        let xt = builder.add_virtual_target();
+        let xt_ext = builder.add_virtual_extension_target();
+        pw.set_target(xt, x)?;
+        pw.set_extension_target(xt_ext, x_ext)?;
+
+        for len in [0, 1, 2, 17] {
+            let coeffs = F::rand_vec(len);
+            let poly = PolynomialCoeffs::new(coeffs.clone());
+
+            let result = builder.constant_poly_eval(&coeffs, xt);
+            let expected = builder.constant(poly.eval(x));
+            builder.connect(result, expected);
+
+            let result_ext = builder.constant_poly_eval_ext(&coeffs, xt_ext);
+            let expected_ext = builder.constant_extension(poly.to_extension::<D>().eval(x_ext));
+            builder.connect_extension(result_ext, expected_ext);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+}
@@ -22,6 +22,27 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         self.split_le(x, n_log);
     }
 
+    /// Checks that `x < 2^n_log` by looking it up in a table of `[0, 2^n_log)`, which is created
+    /// once and shared by all range checks to the same bit width. The table itself takes about
+    /// `2^n_log / 26` rows in the standard configuration, but each check then only takes a lookup
+    /// slot, so this is cheaper than `range_check` when checking many values.
+    pub fn range_check_via_lut(&mut self, x: Target, n_log: usize) {
+        assert!(
+            n_log <= 16,
+            "Lookup tables only support 16-bit inputs, but got a {n_log}-bit range check"
+        );
+        let lut_index = match self.range_check_luts.get(&n_log) {
+            Some(&lut_index) => lut_index,
+            None => {
+                let table = (0..1u32 << n_log).map(|i| i as u16).collect::<Vec<_>>();
+                let lut_index = self.add_lookup_table_from_table(&table, &table);
+                self.range_check_luts.insert(n_log, lut_index);
+                lut_index
+            }
+        };
+        self.add_lookup_from_index(x, lut_index);
+    }
+
     /// Returns the first `num_low_bits` little-endian bits of `x`.
     pub fn low_bits(&mut self, x: Target, num_low_bits: usize, num_bits: usize) -> Vec<BoolTarget> {
         let mut res = self.split_le(x, num_bits);
@@ -109,3 +130,63 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for Low
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::rngs::OsRng;
+    use rand::Rng;
+
+    use super::*;
+    use crate::field::types::Field;
+    use crate::iop::witness::PartialWitness;
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    const D: usize = 2;
+    type C = PoseidonGoldilocksConfig;
+    type F = <C as GenericConfig<D>>::F;
+
+    #[test]
+    fn test_range_check_via_lut() -> Result<()> {
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let xs = builder.add_virtual_targets(100);
+        for &x in &xs {
+            builder.range_check_via_lut(x, 16);
+        }
+        // All checks share the same table.
+        assert_eq!(builder.num_luts(), 1);
+
+        let mut pw = PartialWitness::new();
+        for &x in &xs {
+            pw.set_target(x, F::from_canonical_u16(OsRng.gen()))?;
+        }
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+
+    #[test]
+    fn test_range_check_via_lut_gate_count() {
+        let num_gates = |num_values: usize, via_lut: bool| {
+            let mut builder =
+                CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+            for x in builder.add_virtual_targets(num_values) {
+                if via_lut {
+                    builder.range_check_via_lut(x, 16);
+                } else {
+                    builder.range_check(x, 16);
+                }
+            }
+            builder.add_all_lookups();
+            builder.num_gates()
+        };
+
+        // Decomposing takes a gate per value.
+        assert_eq!(num_gates(100, false), 100);
+        // The table is paid for once, after which each check only takes a lookup slot.
+        let lut_cost = num_gates(200, true) - num_gates(100, true);
+        let decomposition_cost = num_gates(200, false) - num_gates(100, false);
+        assert!(lut_cost * 10 < decomposition_cost);
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 use crate::field::extension::Extendable;
 use crate::hash::hash_types::RichField;
 use crate::iop::ext_target::ExtensionTarget;
@@ -34,13 +37,33 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         let tmp = self.mul_sub(b.target, y, y);
         self.mul_sub(b.target, x, tmp)
     }
+
+    /// Asserts that exactly one of `bits` is set. Each bit is assumed to already be constrained to
+    /// be boolean, so this only constrains their sum to be one.
+    pub fn assert_one_hot(&mut self, bits: &[BoolTarget]) {
+        let sum = self.add_many(bits.iter().map(|b| b.target));
+        self.assert_one(sum);
+    }
+
+    /// Returns the one-hot vector of length `n` whose `index`-th bit is set. Fails if `index` is
+    /// not in `0..n`.
+    pub fn one_hot_select(&mut self, index: Target, n: usize) -> Vec<BoolTarget> {
+        let bits = (0..n)
+            .map(|i| {
+                let i = self.constant(F::from_canonical_usize(i));
+                self.is_equal(index, i)
+            })
+            .collect::<Vec<_>>();
+        self.assert_one_hot(&bits);
+        bits
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
 
-    use crate::field::types::Sample;
+    use crate::field::types::{Field, Sample};
     use crate::iop::witness::{PartialWitness, WitnessWrite};
     use crate::plonk::circuit_builder::CircuitBuilder;
     use crate::plonk::circuit_data::CircuitConfig;
@@ -77,4 +100,65 @@ mod tests {
 
         verify(proof, &data.verifier_only, &data.common)
     }
+
+    #[test]
+    fn test_assert_one_hot() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let prove_one_hot = |values: &[bool]| -> Result<()> {
+            let config = CircuitConfig::standard_recursion_config();
+            let mut pw = PartialWitness::<F>::new();
+            let mut builder = CircuitBuilder::<F, D>::new(config);
+            let bits = (0..values.len())
+                .map(|_| builder.add_virtual_bool_target_safe())
+                .collect::<Vec<_>>();
+            for (&b, &v) in bits.iter().zip(values) {
+                pw.set_bool_target(b, v)?;
+            }
+            builder.assert_one_hot(&bits);
+
+            let data = builder.build::<C>();
+            let proof = data.prove(pw)?;
+            verify(proof, &data.verifier_only, &data.common)
+        };
+
+        prove_one_hot(&[false, false, true, false])?;
+        prove_one_hot(&[true])?;
+        assert!(prove_one_hot(&[false, false, false, false]).is_err());
+        assert!(prove_one_hot(&[true, false, true, false]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_one_hot_select() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let n = 5;
+
+        let prove_one_hot_select = |index: usize| -> Result<()> {
+            let config = CircuitConfig::standard_recursion_config();
+            let mut pw = PartialWitness::<F>::new();
+            let mut builder = CircuitBuilder::<F, D>::new(config);
+            let index_t = builder.add_virtual_target();
+            pw.set_target(index_t, F::from_canonical_usize(index))?;
+            let bits = builder.one_hot_select(index_t, n);
+            for (i, b) in bits.into_iter().enumerate() {
+                let expected = builder.constant_bool(i == index);
+                builder.connect(b.target, expected.target);
+            }
+
+            let data = builder.build::<C>();
+            let proof = data.prove(pw)?;
+            verify(proof, &data.verifier_only, &data.common)
+        };
+
+        for index in 0..n {
+            prove_one_hot_select(index)?;
+        }
+        assert!(prove_one_hot_select(n).is_err());
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter, LowerHex};
 
 use anyhow::ensure;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -112,6 +113,54 @@ impl<F: Field> Default for HashOut<F> {
     }
 }
 
+impl<F: RichField> HashOut<F> {
+    /// Returns the digest as a 256-bit big-endian integer, whose most significant limb is
+    /// `elements[3]` and least significant limb is `elements[0]`.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        self.elements
+            .iter()
+            .rev()
+            .flat_map(|x| x.to_canonical_u64().to_be_bytes())
+            .collect()
+    }
+
+    /// Returns the hex encoding of `to_bytes_be`. Intended for human-readable logging only.
+    pub fn to_hex(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Compares two digests in constant time, i.e. without short-circuiting on the first
+    /// differing element. Prefer this over `==` wherever the timing of a failed comparison could
+    /// leak information.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = self
+            .elements
+            .iter()
+            .zip(&other.elements)
+            .fold(0u64, |acc, (x, y)| {
+                acc | (x.to_canonical_u64() ^ y.to_canonical_u64())
+            });
+        core::hint::black_box(diff) == 0
+    }
+}
+
+impl<F: RichField> LowerHex for HashOut<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.to_bytes_be()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl<F: RichField> Display for HashOut<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
+}
+
 /// Represents a ~256 bit hash output.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct HashOutTarget {
@@ -209,3 +258,49 @@ impl<'de, const N: usize> Deserialize<'de> for BytesHash<N> {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_out_hex() {
+        type F = GoldilocksField;
+
+        let hash = HashOut::from([
+            F::from_canonical_u64(1),
+            F::from_canonical_u64(0x0123456789abcdef),
+            F::ZERO,
+            F::NEG_ONE,
+        ]);
+        let expected = concat!(
+            "ffffffff00000000",
+            "0000000000000000",
+            "0123456789abcdef",
+            "0000000000000001",
+        );
+
+        let bytes = hash.to_bytes_be();
+        assert_eq!(bytes[..8], F::NEG_ONE.to_canonical_u64().to_be_bytes());
+        assert_eq!(bytes[24..], 1u64.to_be_bytes());
+        assert_eq!(hash.to_hex(), expected);
+        assert_eq!(format!("{hash:x}"), expected);
+        assert_eq!(format!("{hash}"), format!("0x{expected}"));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        type F = GoldilocksField;
+
+        let hash = HashOut::<F>::rand();
+        assert!(hash.ct_eq(&hash));
+        assert_eq!(hash.ct_eq(&hash), hash == hash);
+
+        for i in 0..NUM_HASH_OUT_ELTS {
+            let mut other = hash;
+            other.elements[i] += F::ONE;
+            assert!(!other.ct_eq(&hash));
+            assert_eq!(other.ct_eq(&hash), other == hash);
+        }
+    }
+}
@@ -3,6 +3,8 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
+use hashbrown::HashMap;
+
 use crate::field::extension::Extendable;
 use crate::field::types::Field;
 use crate::hash::hash_types::{HashOut, HashOutTarget, RichField, NUM_HASH_OUT_ELTS};
@@ -20,6 +22,23 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         }
     }
 
+    /// Applies `hash_or_noop` to each of `inputs`. Slices made of the same targets are only hashed
+    /// once, and share the resulting permutation gates.
+    pub fn hash_or_noop_many<H: AlgebraicHasher<F>>(
+        &mut self,
+        inputs: &[Vec<Target>],
+    ) -> Vec<HashOutTarget> {
+        let mut cache = HashMap::<&[Target], HashOutTarget>::new();
+        inputs
+            .iter()
+            .map(|input| {
+                *cache
+                    .entry(input)
+                    .or_insert_with(|| self.hash_or_noop::<H>(input.clone()))
+            })
+            .collect()
+    }
+
     pub fn hash_n_to_hash_no_pad<H: AlgebraicHasher<F>>(
         &mut self,
         inputs: Vec<Target>,
@@ -143,3 +162,50 @@ pub fn hash_n_to_m_no_pad<F: RichField, P: PlonkyPermutation<F>>(
 pub fn hash_n_to_hash_no_pad<F: RichField, P: PlonkyPermutation<F>>(inputs: &[F]) -> HashOut<F> {
     HashOut::from_vec(hash_n_to_m_no_pad::<F, P>(inputs, NUM_HASH_OUT_ELTS))
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::types::Sample;
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    #[test]
+    fn test_hash_or_noop_many() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type H = <C as GenericConfig<D>>::InnerHasher;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        // Leaves of various sizes, both below and above the no-op threshold, with one repeat.
+        let mut leaves = [1, 3, 4, 5, 8, 12, 20]
+            .into_iter()
+            .map(|n| builder.add_virtual_targets(n))
+            .collect::<Vec<_>>();
+        for leaf in &leaves {
+            for &t in leaf {
+                pw.set_target(t, F::rand())?;
+            }
+        }
+        leaves.push(leaves[4].clone());
+
+        let hashes = builder.hash_or_noop_many::<H>(&leaves);
+        assert_eq!(hashes.len(), leaves.len());
+        assert_eq!(hashes[4], hashes[7]);
+        for (leaf, hash) in leaves.iter().zip(hashes) {
+            let expected = builder.hash_or_noop::<H>(leaf.clone());
+            builder.connect_hashes(hash, expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+}
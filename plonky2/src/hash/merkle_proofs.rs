@@ -139,6 +139,86 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         );
     }
 
+    /// Like `verify_merkle_proof_to_cap`, but the index is given as a single target, which is split
+    /// into `num_index_bits` little-endian bits.
+    pub fn verify_merkle_proof_to_cap_with_index<H: AlgebraicHasher<F>>(
+        &mut self,
+        leaf_data: Vec<Target>,
+        leaf_index: Target,
+        num_index_bits: usize,
+        merkle_cap: &MerkleCapTarget,
+        proof: &MerkleProofTarget,
+    ) {
+        let leaf_index_bits = self.split_le(leaf_index, num_index_bits);
+        self.verify_merkle_proof_to_cap::<H>(leaf_data, &leaf_index_bits, merkle_cap, proof);
+    }
+
+    /// Verifies several Merkle proofs against the same cap. The index of each leaf is given by its
+    /// little-endian bits. When two leaves use the same index bit targets from some layer up, their
+    /// paths go through the same internal nodes from that layer on. The shared part of the path,
+    /// including the cap lookup, is then only computed once.
+    pub fn verify_merkle_proofs_batch<H: AlgebraicHasher<F>>(
+        &mut self,
+        leaves: &[Vec<Target>],
+        leaf_index_bits: &[&[BoolTarget]],
+        merkle_cap: &MerkleCapTarget,
+        proofs: &[MerkleProofTarget],
+    ) {
+        debug_assert!(H::AlgebraicPermutation::RATE >= NUM_HASH_OUT_ELTS);
+        assert_eq!(leaves.len(), leaf_index_bits.len());
+        assert_eq!(leaves.len(), proofs.len());
+
+        let zero = self.zero();
+        // The index bits of each verified path, along with the digests computed along it, starting
+        // from the leaf hash.
+        let mut paths: Vec<(&[BoolTarget], Vec<HashOutTarget>)> = Vec::new();
+        for ((leaf_data, &index_bits), proof) in leaves.iter().zip(leaf_index_bits).zip(proofs) {
+            let height = proof.siblings.len();
+            // The lowest layer at which this path joins an already computed one, if any.
+            let shared = paths
+                .iter()
+                .filter(|(bits, _)| bits.len() == index_bits.len())
+                .filter_map(|(bits, digests)| {
+                    let layer = (0..=height).find(|&l| bits[l..] == index_bits[l..])?;
+                    digests.get(layer).map(|&digest| (layer, digest))
+                })
+                .min_by_key(|&(layer, _)| layer);
+
+            let num_layers = shared.map_or(height, |(layer, _)| layer);
+            let mut digests = vec![self.hash_or_noop::<H>(leaf_data.clone())];
+            for (&bit, &sibling) in index_bits[..num_layers].iter().zip(&proof.siblings) {
+                let state = *digests.last().unwrap();
+                let mut perm_inputs = H::AlgebraicPermutation::default();
+                perm_inputs.set_from_slice(&state.elements, 0);
+                perm_inputs.set_from_slice(&sibling.elements, NUM_HASH_OUT_ELTS);
+                // Ensure the rest of the state, if any, is zero:
+                perm_inputs.set_from_iter(core::iter::repeat(zero), 2 * NUM_HASH_OUT_ELTS);
+                let perm_outs = self.permute_swapped::<H>(perm_inputs, bit);
+                let hash_outs = perm_outs.squeeze()[0..NUM_HASH_OUT_ELTS]
+                    .try_into()
+                    .unwrap();
+                digests.push(HashOutTarget {
+                    elements: hash_outs,
+                });
+            }
+
+            let state = *digests.last().unwrap();
+            if let Some((_, shared_digest)) = shared {
+                self.connect_hashes(state, shared_digest);
+            } else {
+                let cap_index = self.le_sum(index_bits[height..].iter().copied());
+                for i in 0..NUM_HASH_OUT_ELTS {
+                    let result = self.random_access(
+                        cap_index,
+                        merkle_cap.0.iter().map(|h| h.elements[i]).collect(),
+                    );
+                    self.connect(result, state.elements[i]);
+                }
+            }
+            paths.push((index_bits, digests));
+        }
+    }
+
     /// Same as `verify_merkle_proof_to_cap`, except with the final "cap index" as separate parameter,
     /// rather than being contained in `leaf_index_bits`.
     pub(crate) fn verify_merkle_proof_to_cap_with_cap_index<H: AlgebraicHasher<F>>(
@@ -373,4 +453,137 @@ mod tests {
 
         verify(proof, &data.verifier_only, &data.common)
     }
+
+    #[test]
+    fn test_recursive_merkle_proof_with_index() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let log_n = 8;
+        let n = 1 << log_n;
+        let cap_height = 1;
+        let leaves = random_data::<F>(n, 7);
+        let tree = MerkleTree::<F, <C as GenericConfig<D>>::Hasher>::new(leaves, cap_height);
+        let i: usize = OsRng.gen_range(0..n);
+        let proof = tree.prove(i);
+
+        let proof_t = MerkleProofTarget {
+            siblings: builder.add_virtual_hashes(proof.siblings.len()),
+        };
+        for i in 0..proof.siblings.len() {
+            pw.set_hash_target(proof_t.siblings[i], proof.siblings[i])?;
+        }
+
+        let cap_t = builder.add_virtual_cap(cap_height);
+        pw.set_cap_target(&cap_t, &tree.cap)?;
+
+        let i_t = builder.add_virtual_target();
+        pw.set_target(i_t, F::from_canonical_usize(i))?;
+
+        let data = builder.add_virtual_targets(tree.leaves[i].len());
+        for j in 0..data.len() {
+            pw.set_target(data[j], tree.leaves[i][j])?;
+        }
+
+        builder.verify_merkle_proof_to_cap_with_index::<<C as GenericConfig<D>>::InnerHasher>(
+            data, i_t, log_n, &cap_t, &proof_t,
+        );
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
+    #[test]
+    fn test_recursive_merkle_proofs_batch() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type H = <C as GenericConfig<D>>::InnerHasher;
+        let config = CircuitConfig::standard_recursion_config();
+
+        let log_n = 4;
+        let n = 1 << log_n;
+        let cap_height = 1;
+        let num_leaves = 4;
+        let leaves = random_data::<F>(n, 7);
+        let tree = MerkleTree::<F, <C as GenericConfig<D>>::Hasher>::new(leaves, cap_height);
+
+        let build = |batched: bool| -> Result<(CircuitBuilder<F, D>, PartialWitness<F>)> {
+            let mut pw = PartialWitness::new();
+            let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+            let cap_t = builder.add_virtual_cap(cap_height);
+            pw.set_cap_target(&cap_t, &tree.cap)?;
+
+            // Leaves 0 to 3 share their upper index bits, so leaf `i` uses the bit targets
+            // `[b0_i, b1_{i/2}, b2, b3]`.
+            let b0 = (0..num_leaves)
+                .map(|_| builder.add_virtual_bool_target_safe())
+                .collect::<Vec<_>>();
+            let b1 = (0..num_leaves / 2)
+                .map(|_| builder.add_virtual_bool_target_safe())
+                .collect::<Vec<_>>();
+            let upper = [
+                builder.add_virtual_bool_target_safe(),
+                builder.add_virtual_bool_target_safe(),
+            ];
+            for i in 0..num_leaves {
+                pw.set_bool_target(b0[i], i & 1 == 1)?;
+            }
+            for i in 0..num_leaves / 2 {
+                pw.set_bool_target(b1[i], i & 1 == 1)?;
+            }
+            for b in upper {
+                pw.set_bool_target(b, false)?;
+            }
+            let index_bits = (0..num_leaves)
+                .map(|i| vec![b0[i], b1[i / 2], upper[0], upper[1]])
+                .collect::<Vec<_>>();
+
+            let mut data_t = Vec::new();
+            let mut proofs_t = Vec::new();
+            for i in 0..num_leaves {
+                let proof = tree.prove(i);
+                let proof_t = MerkleProofTarget {
+                    siblings: builder.add_virtual_hashes(proof.siblings.len()),
+                };
+                for (&t, &h) in proof_t.siblings.iter().zip(&proof.siblings) {
+                    pw.set_hash_target(t, h)?;
+                }
+                let data = builder.add_virtual_targets(tree.leaves[i].len());
+                pw.set_target_arr(&data, &tree.leaves[i])?;
+                data_t.push(data);
+                proofs_t.push(proof_t);
+            }
+
+            if batched {
+                let index_bits = index_bits.iter().map(|b| b.as_slice()).collect::<Vec<_>>();
+                builder.verify_merkle_proofs_batch::<H>(&data_t, &index_bits, &cap_t, &proofs_t);
+            } else {
+                for i in 0..num_leaves {
+                    builder.verify_merkle_proof_to_cap::<H>(
+                        data_t[i].clone(),
+                        &index_bits[i],
+                        &cap_t,
+                        &proofs_t[i],
+                    );
+                }
+            }
+            Ok((builder, pw))
+        };
+
+        let (independent_builder, _) = build(false)?;
+        let (builder, pw) = build(true)?;
+        assert!(builder.num_gates() < independent_builder.num_gates());
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
 }
@@ -1,5 +1,6 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter, LowerHex};
 use core::mem::MaybeUninit;
 use core::slice;
 
@@ -7,7 +8,7 @@ use plonky2_maybe_rayon::*;
 use serde::{Deserialize, Serialize};
 
 use crate::hash::hash_types::RichField;
-use crate::hash::merkle_proofs::MerkleProof;
+use crate::hash::merkle_proofs::{verify_merkle_proof_to_cap, MerkleProof};
 use crate::plonk::config::{GenericHashOut, Hasher};
 use crate::util::log2_strict;
 
@@ -40,6 +41,46 @@ impl<F: RichField, H: Hasher<F>> MerkleCap<F, H> {
     pub fn flatten(&self) -> Vec<F> {
         self.0.iter().flat_map(|&h| h.to_vec()).collect()
     }
+
+    /// Checks that `leaf` is present at `index` in a Merkle tree with this cap. Malformed proofs,
+    /// including ones with more siblings than `index` has bits, are rejected rather than panicking.
+    pub fn verify_membership(&self, leaf: &[F], index: usize, proof: &MerkleProof<F, H>) -> bool {
+        u32::try_from(proof.len())
+            .ok()
+            .and_then(|num_siblings| index.checked_shr(num_siblings))
+            .is_some_and(|cap_index| cap_index < self.len())
+            && verify_merkle_proof_to_cap(leaf.to_vec(), index, self, proof).is_ok()
+    }
+}
+
+/// Formats the cap as a list of hex digests.
+impl<F: RichField, H: Hasher<F>> LowerHex for MerkleCap<F, H>
+where
+    H::Hash: LowerHex,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, hash) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if f.alternate() {
+                write!(f, "{hash:#x}")?;
+            } else {
+                write!(f, "{hash:x}")?;
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+impl<F: RichField, H: Hasher<F>> Display for MerkleCap<F, H>
+where
+    H::Hash: LowerHex,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -243,7 +284,11 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::field::extension::Extendable;
-    use crate::hash::merkle_proofs::verify_merkle_proof_to_cap;
+    use crate::field::goldilocks_field::GoldilocksField;
+    use crate::field::types::Field;
+    use crate::hash::hash_types::HashOut;
+    use crate::hash::keccak::KeccakHash;
+    use crate::hash::poseidon::PoseidonHash;
     use crate::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
 
     pub(crate) fn random_data<F: RichField>(n: usize, k: usize) -> Vec<Vec<F>> {
@@ -309,4 +354,84 @@ pub(crate) mod tests {
 
         Ok(())
     }
+
+    fn check_two_to_one<F: RichField, H: Hasher<F>>() {
+        let leaves = random_data::<F>(8, 7);
+        let tree = MerkleTree::<F, H>::new(leaves.clone(), 0);
+
+        let mut layer = leaves
+            .iter()
+            .map(|l| H::hash_or_noop(l))
+            .collect::<Vec<_>>();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| H::two_to_one(pair[0], pair[1]))
+                .collect();
+        }
+        assert_eq!(tree.cap.0, layer);
+    }
+
+    #[test]
+    fn test_two_to_one_matches_merkle_tree() {
+        type F = GoldilocksField;
+
+        check_two_to_one::<F, PoseidonHash>();
+        check_two_to_one::<F, KeccakHash<25>>();
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let log_n = 6;
+        let n = 1 << log_n;
+        let cap_height = 2;
+        let leaves = random_data::<F>(n, 7);
+        let tree =
+            MerkleTree::<F, <C as GenericConfig<D>>::Hasher>::new(leaves.clone(), cap_height);
+
+        let i = 37;
+        let proof = tree.prove(i);
+        assert!(tree.cap.verify_membership(&leaves[i], i, &proof));
+        assert!(!tree.cap.verify_membership(&leaves[i + 1], i, &proof));
+        assert!(!tree.cap.verify_membership(&leaves[i], i + 1, &proof));
+        assert!(!tree.cap.verify_membership(&leaves[i], i + n, &proof));
+
+        // A proof with more siblings than the index has bits is rejected.
+        let oversized_proof = MerkleProof {
+            siblings: vec![proof.siblings[0]; usize::BITS as usize],
+        };
+        assert!(!tree.cap.verify_membership(&leaves[i], i, &oversized_proof));
+        let oversized_proof = MerkleProof {
+            siblings: vec![proof.siblings[0]; 100],
+        };
+        assert!(!tree.cap.verify_membership(&leaves[i], i, &oversized_proof));
+    }
+
+    #[test]
+    fn test_merkle_cap_hex() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type H = <C as GenericConfig<D>>::Hasher;
+
+        let cap = MerkleCap::<F, H>(vec![
+            HashOut::from([F::ONE, F::ZERO, F::ZERO, F::ZERO]),
+            HashOut::from([F::ZERO, F::ZERO, F::ZERO, F::NEG_ONE]),
+        ]);
+
+        assert_eq!(
+            format!("{cap}"),
+            "[0x0000000000000000000000000000000000000000000000000000000000000001, \
+             0xffffffff00000000000000000000000000000000000000000000000000000000]"
+        );
+        assert_eq!(
+            format!("{cap:x}"),
+            "[0000000000000000000000000000000000000000000000000000000000000001, \
+             ffffffff00000000000000000000000000000000000000000000000000000000]"
+        );
+    }
 }
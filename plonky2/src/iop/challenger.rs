@@ -36,6 +36,19 @@ impl<F: RichField, H: Hasher<F>> Challenger<F, H> {
         }
     }
 
+    /// Creates a challenger whose transcript starts with the domain separator `domain`, so that
+    /// transcripts of different protocols differ even when all of their later inputs coincide.
+    /// The domain is absorbed on its own, so it can't be confused with a prefix of those inputs.
+    /// An empty domain gives the same challenger as `new`.
+    pub fn new_with_domain(domain: &[F]) -> Challenger<F, H> {
+        let mut challenger = Self::new();
+        challenger.observe_elements(domain);
+        if !challenger.input_buffer.is_empty() {
+            challenger.duplexing();
+        }
+        challenger
+    }
+
     pub fn observe_element(&mut self, element: F) {
         // Any buffered outputs are now invalid, since they wouldn't reflect this input.
         self.output_buffer.clear();
@@ -47,6 +60,8 @@ impl<F: RichField, H: Hasher<F>> Challenger<F, H> {
         }
     }
 
+    /// Observes an extension field element as its `D` base field components, starting with the
+    /// constant coefficient, i.e. in the order given by `to_basefield_array`.
     pub fn observe_extension_element<const D: usize>(&mut self, element: &F::Extension)
     where
         F: RichField + Extendable<D>,
@@ -60,6 +75,7 @@ impl<F: RichField, H: Hasher<F>> Challenger<F, H> {
         }
     }
 
+    /// Observes each extension field element in turn, as in `observe_extension_element`.
     pub fn observe_extension_elements<const D: usize>(&mut self, elements: &[F::Extension])
     where
         F: RichField + Extendable<D>,
@@ -183,6 +199,14 @@ impl<F: RichField + Extendable<D>, H: AlgebraicHasher<F>, const D: usize>
         }
     }
 
+    /// The recursive analogue of `Challenger::new_with_domain`.
+    pub fn new_with_domain(builder: &mut CircuitBuilder<F, D>, domain: &[F]) -> Self {
+        let mut challenger = Self::new(builder);
+        challenger.observe_elements(&builder.constants(domain));
+        challenger.absorb_buffered_inputs(builder);
+        challenger
+    }
+
     pub fn from_state(sponge_state: H::AlgebraicPermutation) -> Self {
         Self {
             sponge_state,
@@ -215,10 +239,13 @@ impl<F: RichField + Extendable<D>, H: AlgebraicHasher<F>, const D: usize>
         }
     }
 
+    /// Observes an extension target as its `D` base field components, in the same order as
+    /// `Challenger::observe_extension_element`.
     pub fn observe_extension_element(&mut self, element: ExtensionTarget<D>) {
         self.observe_elements(&element.0);
     }
 
+    /// Observes each extension target in turn, as in `observe_extension_element`.
     pub fn observe_extension_elements(&mut self, elements: &[ExtensionTarget<D>]) {
         for &element in elements {
             self.observe_extension_element(element);
@@ -297,7 +324,10 @@ mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec::Vec;
 
+    use crate::field::extension::{Extendable, FieldExtension};
     use crate::field::types::Sample;
+    use crate::hash::hash_types::{HashOut, HashOutTarget, MerkleCapTarget};
+    use crate::hash::merkle_tree::MerkleCap;
     use crate::iop::challenger::{Challenger, RecursiveChallenger};
     use crate::iop::generator::generate_partial_witness;
     use crate::iop::target::Target;
@@ -374,4 +404,157 @@ mod tests {
 
         assert_eq!(outputs_per_round, recursive_output_values_per_round);
     }
+
+    /// Tests that observing a cap is the same as observing each of its hashes in turn.
+    #[test]
+    fn test_observe_cap() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type H = <C as GenericConfig<D>>::InnerHasher;
+
+        let cap = MerkleCap::<F, H>((0..4).map(|_| HashOut::rand()).collect());
+        let num_outputs = 3;
+
+        let mut challenger = Challenger::<F, H>::new();
+        challenger.observe_cap(&cap);
+        let outputs = challenger.get_n_challenges(num_outputs);
+
+        let mut manual_challenger = Challenger::<F, H>::new();
+        for &hash in &cap.0 {
+            manual_challenger.observe_hash::<H>(hash);
+        }
+        assert_eq!(manual_challenger.get_n_challenges(num_outputs), outputs);
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let cap_t = MerkleCapTarget(
+            cap.0
+                .iter()
+                .map(|h| HashOutTarget::from_vec(builder.constants(&h.elements)))
+                .collect(),
+        );
+        let mut recursive_challenger = RecursiveChallenger::<F, H, D>::new(&mut builder);
+        recursive_challenger.observe_cap(&cap_t);
+        let recursive_outputs = recursive_challenger.get_n_challenges(&mut builder, num_outputs);
+
+        let mut manual_recursive_challenger = RecursiveChallenger::<F, H, D>::new(&mut builder);
+        for hash in &cap_t.0 {
+            manual_recursive_challenger.observe_hash(hash);
+        }
+        let manual_recursive_outputs =
+            manual_recursive_challenger.get_n_challenges(&mut builder, num_outputs);
+
+        let circuit = builder.build::<C>();
+        let witness =
+            generate_partial_witness(PartialWitness::new(), &circuit.prover_only, &circuit.common)
+                .unwrap();
+        assert_eq!(witness.get_targets(&recursive_outputs), outputs);
+        assert_eq!(witness.get_targets(&manual_recursive_outputs), outputs);
+    }
+
+    /// Tests that observing extension elements is the same as observing their base field
+    /// components, both natively and recursively.
+    #[test]
+    fn test_observe_extension_elements() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type FE = <F as Extendable<D>>::Extension;
+        type H = <C as GenericConfig<D>>::InnerHasher;
+
+        let elements = FE::rand_vec(5);
+        let num_outputs = 3;
+
+        let mut challenger = Challenger::<F, H>::new();
+        challenger.observe_extension_elements::<D>(&elements);
+        let outputs = challenger.get_n_challenges(num_outputs);
+
+        let mut manual_challenger = Challenger::<F, H>::new();
+        for element in &elements {
+            manual_challenger.observe_elements(&FieldExtension::<D>::to_basefield_array(element));
+        }
+        assert_eq!(manual_challenger.get_n_challenges(num_outputs), outputs);
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let elements_t = elements
+            .iter()
+            .map(|&e| builder.constant_extension(e))
+            .collect::<Vec<_>>();
+        let mut recursive_challenger = RecursiveChallenger::<F, H, D>::new(&mut builder);
+        recursive_challenger.observe_extension_elements(&elements_t);
+        let recursive_outputs = recursive_challenger.get_n_challenges(&mut builder, num_outputs);
+
+        let circuit = builder.build::<C>();
+        let witness =
+            generate_partial_witness(PartialWitness::new(), &circuit.prover_only, &circuit.common)
+                .unwrap();
+        assert_eq!(witness.get_targets(&recursive_outputs), outputs);
+    }
+
+    /// Tests that domain separation changes the challenges, and that `Challenger` and
+    /// `RecursiveChallenger` agree on it.
+    #[test]
+    fn test_new_with_domain() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type H = <C as GenericConfig<D>>::InnerHasher;
+
+        let inputs = F::rand_vec(5);
+        let num_outputs = 3;
+        let challenges = |mut challenger: Challenger<F, H>| {
+            challenger.observe_elements(&inputs);
+            challenger.get_n_challenges(num_outputs)
+        };
+
+        // Domains shorter than, equal to and longer than the sponge rate.
+        let domains = [
+            F::rand_vec(1),
+            F::rand_vec(1),
+            F::rand_vec(8),
+            F::rand_vec(11),
+        ];
+        let outputs = domains
+            .iter()
+            .map(|domain| challenges(Challenger::new_with_domain(domain)))
+            .collect::<Vec<_>>();
+        for i in 0..outputs.len() {
+            for j in 0..i {
+                assert_ne!(outputs[i], outputs[j]);
+            }
+        }
+
+        assert_eq!(
+            challenges(Challenger::new_with_domain(&[])),
+            challenges(Challenger::new())
+        );
+
+        // The domain is not just a prefix of the transcript.
+        let mut prefixed = Challenger::<F, H>::new();
+        prefixed.observe_elements(&domains[0]);
+        assert_ne!(challenges(prefixed), outputs[0]);
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let inputs_t = builder.constants(&inputs);
+        let recursive_outputs = domains
+            .iter()
+            .map(|domain| {
+                let mut challenger =
+                    RecursiveChallenger::<F, H, D>::new_with_domain(&mut builder, domain);
+                challenger.observe_elements(&inputs_t);
+                challenger.get_n_challenges(&mut builder, num_outputs)
+            })
+            .collect::<Vec<_>>();
+
+        let circuit = builder.build::<C>();
+        let witness =
+            generate_partial_witness(PartialWitness::new(), &circuit.prover_only, &circuit.common)
+                .unwrap();
+        for (recursive, expected) in recursive_outputs.iter().zip(&outputs) {
+            assert_eq!(&witness.get_targets(recursive), expected);
+        }
+    }
 }
@@ -1,14 +1,22 @@
 #[cfg(not(feature = "std"))]
 use alloc::{
     boxed::Box,
+    collections::BTreeMap,
     string::{String, ToString},
+    sync::Arc,
     vec,
     vec::Vec,
 };
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{collections::BTreeMap, sync::Arc};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
+use rand::RngCore;
+#[cfg(feature = "timing")]
+use web_time::Instant;
 
 use crate::field::extension::Extendable;
 use crate::field::types::Field;
@@ -19,7 +27,10 @@ use crate::iop::wire::Wire;
 use crate::iop::witness::{PartialWitness, PartitionWitness, Witness, WitnessWrite};
 use crate::plonk::circuit_data::{CommonCircuitData, ProverOnlyCircuitData};
 use crate::plonk::config::GenericConfig;
-use crate::util::serialization::{Buffer, IoResult, Read, Write};
+use crate::util::serialization::{Buffer, IoError, IoResult, Read, Write};
+
+/// The wall-clock time spent running each type of generator, keyed by generator id.
+pub type GeneratorTimings = BTreeMap<String, Duration>;
 
 /// Given a `PartitionWitness` that has only inputs set, populates the rest of the witness using the
 /// given set of generators.
@@ -33,6 +44,66 @@ pub fn generate_partial_witness<
     prover_data: &'a ProverOnlyCircuitData<F, C, D>,
     common_data: &'a CommonCircuitData<F, D>,
 ) -> Result<PartitionWitness<'a, F>> {
+    generate_partial_witness_helper(inputs, prover_data, common_data, None, None)
+}
+
+/// Same as `generate_partial_witness`, but runs generators with `WitnessGenerator::run_with_rng`,
+/// so that any randomness they sample comes from `rng` and the witness is reproducible for a given
+/// seed.
+pub fn generate_partial_witness_with_rng<
+    'a,
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    R: RngCore,
+    const D: usize,
+>(
+    inputs: PartialWitness<F>,
+    prover_data: &'a ProverOnlyCircuitData<F, C, D>,
+    common_data: &'a CommonCircuitData<F, D>,
+    rng: &mut R,
+) -> Result<PartitionWitness<'a, F>> {
+    generate_partial_witness_helper(inputs, prover_data, common_data, None, Some(rng))
+}
+
+/// Same as `generate_partial_witness`, but also returns the time spent running each type of
+/// generator. Timings are only recorded if the `timing` feature is enabled; without it, the
+/// returned `GeneratorTimings` is always empty.
+pub fn generate_partial_witness_with_timings<
+    'a,
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
+>(
+    inputs: PartialWitness<F>,
+    prover_data: &'a ProverOnlyCircuitData<F, C, D>,
+    common_data: &'a CommonCircuitData<F, D>,
+) -> Result<(PartitionWitness<'a, F>, GeneratorTimings)> {
+    let mut generator_timings = GeneratorTimings::new();
+    let witness = generate_partial_witness_helper(
+        inputs,
+        prover_data,
+        common_data,
+        Some(&mut generator_timings),
+        None,
+    )?;
+    Ok((witness, generator_timings))
+}
+
+fn generate_partial_witness_helper<
+    'a,
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
+>(
+    inputs: PartialWitness<F>,
+    prover_data: &'a ProverOnlyCircuitData<F, C, D>,
+    common_data: &'a CommonCircuitData<F, D>,
+    mut generator_timings: Option<&mut GeneratorTimings>,
+    mut rng: Option<&mut dyn RngCore>,
+) -> Result<PartitionWitness<'a, F>> {
+    #[cfg(not(feature = "timing"))]
+    let _ = generator_timings.take();
+
     let config = &common_data.config;
     let generators = &prover_data.generators;
     let generator_indices_by_watches = &prover_data.generator_indices_by_watches;
@@ -66,7 +137,17 @@ pub fn generate_partial_witness<
                 continue;
             }
 
-            let finished = generators[generator_idx].0.run(&witness, &mut buffer);
+            let generator = &generators[generator_idx].0;
+            #[cfg(feature = "timing")]
+            let start = generator_timings.is_some().then(Instant::now);
+            let finished = match rng.as_deref_mut() {
+                Some(rng) => generator.run_with_rng(&witness, &mut buffer, rng),
+                None => generator.run(&witness, &mut buffer),
+            };
+            #[cfg(feature = "timing")]
+            if let (Some(timings), Some(start)) = (generator_timings.as_deref_mut(), start) {
+                *timings.entry(generator.id()).or_default() += start.elapsed();
+            }
             if finished {
                 generator_is_expired[generator_idx] = true;
                 remaining_generators -= 1;
@@ -103,6 +184,65 @@ pub fn generate_partial_witness<
     Ok(witness)
 }
 
+/// Looks for a cycle of generators, each watching a target populated by the previous one, given a
+/// map from each target to the index of its representative. Such generators would wait on each
+/// other forever. Only the targets declared by `WitnessGenerator::outputs` are considered.
+pub(crate) fn find_generator_cycle<F: RichField + Extendable<D>, const D: usize>(
+    generators: &[WitnessGeneratorRef<F, D>],
+    generator_indices_by_watches: &BTreeMap<usize, Vec<usize>>,
+    representative: impl Fn(Target) -> usize,
+) -> Option<Vec<usize>> {
+    let successors = |i: usize| -> Vec<usize> {
+        generators[i]
+            .0
+            .outputs()
+            .into_iter()
+            .flat_map(|t| {
+                generator_indices_by_watches
+                    .get(&representative(t))
+                    .into_iter()
+                    .flatten()
+                    .copied()
+            })
+            .filter(|&j| j != i)
+            .collect()
+    };
+
+    // Iterative depth-first search, where `on_path[i]` is set while generator `i` is on the
+    // current path.
+    let mut visited = vec![false; generators.len()];
+    let mut on_path = vec![false; generators.len()];
+    for root in 0..generators.len() {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        on_path[root] = true;
+        let mut path = vec![root];
+        let mut stack = vec![successors(root)];
+        while let Some(next) = stack.last_mut() {
+            match next.pop() {
+                Some(j) if on_path[j] => {
+                    let start = path.iter().position(|&i| i == j).unwrap();
+                    return Some(path[start..].to_vec());
+                }
+                Some(j) if !visited[j] => {
+                    visited[j] = true;
+                    on_path[j] = true;
+                    path.push(j);
+                    stack.push(successors(j));
+                }
+                Some(_) => {}
+                None => {
+                    on_path[path.pop().unwrap()] = false;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    None
+}
+
 /// A generator participates in the generation of the witness.
 pub trait WitnessGenerator<F: RichField + Extendable<D>, const D: usize>:
     'static + Send + Sync + Debug
@@ -113,11 +253,29 @@ pub trait WitnessGenerator<F: RichField + Extendable<D>, const D: usize>:
     /// the generator will be queued to run.
     fn watch_list(&self) -> Vec<Target>;
 
+    /// Targets this generator is known to populate. This is only used to detect dependency cycles
+    /// between generators when building a circuit, so generators may leave it empty.
+    fn outputs(&self) -> Vec<Target> {
+        Vec::new()
+    }
+
     /// Run this generator, returning a flag indicating whether the generator is finished. If the
     /// flag is true, the generator will never be run again, otherwise it will be queued for another
     /// run next time a target in its watch list is populated.
     fn run(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) -> bool;
 
+    /// Same as `run`, but any randomness the generator needs is drawn from `rng`, so that the
+    /// generated values are reproducible for a given seed. Generators which don't sample
+    /// randomness can rely on the default, which ignores `rng`.
+    fn run_with_rng(
+        &self,
+        witness: &PartitionWitness<F>,
+        out_buffer: &mut GeneratedValues<F>,
+        _rng: &mut dyn RngCore,
+    ) -> bool {
+        self.run(witness, out_buffer)
+    }
+
     fn serialize(&self, dst: &mut Vec<u8>, common_data: &CommonCircuitData<F, D>) -> IoResult<()>;
 
     fn deserialize(src: &mut Buffer, common_data: &CommonCircuitData<F, D>) -> IoResult<Self>
@@ -210,12 +368,28 @@ pub trait SimpleGenerator<F: RichField + Extendable<D>, const D: usize>:
 
     fn dependencies(&self) -> Vec<Target>;
 
+    /// Targets this generator is known to populate; see `WitnessGenerator::outputs`.
+    fn outputs(&self) -> Vec<Target> {
+        Vec::new()
+    }
+
     fn run_once(
         &self,
         witness: &PartitionWitness<F>,
         out_buffer: &mut GeneratedValues<F>,
     ) -> Result<()>;
 
+    /// Same as `run_once`, but any randomness is drawn from `rng`; see
+    /// `WitnessGenerator::run_with_rng`.
+    fn run_once_with_rng(
+        &self,
+        witness: &PartitionWitness<F>,
+        out_buffer: &mut GeneratedValues<F>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<()> {
+        self.run_once(witness, out_buffer)
+    }
+
     fn adapter(self) -> SimpleGeneratorAdapter<F, Self, D>
     where
         Self: Sized,
@@ -254,6 +428,10 @@ impl<F: RichField + Extendable<D>, SG: SimpleGenerator<F, D>, const D: usize> Wi
         self.inner.dependencies()
     }
 
+    fn outputs(&self) -> Vec<Target> {
+        self.inner.outputs()
+    }
+
     fn run(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) -> bool {
         if witness.contains_all(&self.inner.dependencies()) {
             self.inner.run_once(witness, out_buffer).is_ok()
@@ -262,6 +440,21 @@ impl<F: RichField + Extendable<D>, SG: SimpleGenerator<F, D>, const D: usize> Wi
         }
     }
 
+    fn run_with_rng(
+        &self,
+        witness: &PartitionWitness<F>,
+        out_buffer: &mut GeneratedValues<F>,
+        rng: &mut dyn RngCore,
+    ) -> bool {
+        if witness.contains_all(&self.inner.dependencies()) {
+            self.inner
+                .run_once_with_rng(witness, out_buffer, rng)
+                .is_ok()
+        } else {
+            false
+        }
+    }
+
     fn serialize(&self, dst: &mut Vec<u8>, common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
         self.inner.serialize(dst, common_data)
     }
@@ -290,6 +483,10 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for Cop
         vec![self.src]
     }
 
+    fn outputs(&self) -> Vec<Target> {
+        vec![self.dst]
+    }
+
     fn run_once(
         &self,
         witness: &PartitionWitness<F>,
@@ -335,6 +532,15 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for Ran
         out_buffer.set_target(self.target, random_value)
     }
 
+    fn run_once_with_rng(
+        &self,
+        _witness: &PartitionWitness<F>,
+        out_buffer: &mut GeneratedValues<F>,
+        rng: &mut dyn RngCore,
+    ) -> Result<()> {
+        out_buffer.set_target(self.target, F::sample(rng))
+    }
+
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
         dst.write_target(self.target)
     }
@@ -441,3 +647,68 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for Con
         })
     }
 }
+
+/// A closure computing the outputs of a `ClosureGenerator` from the values of its dependencies.
+pub type WitnessFn<F> = Arc<dyn Fn(&[F]) -> Vec<F> + Send + Sync>;
+
+/// Generator which computes its outputs by calling an arbitrary closure on the values of its
+/// dependencies.
+///
+/// Closures can't be serialized, so neither can circuits containing this generator. Circuits
+/// that need to be serialized should implement `SimpleGenerator` directly instead.
+#[derive(Clone)]
+pub struct ClosureGenerator<F: Field> {
+    pub(crate) deps: Vec<Target>,
+    pub(crate) outputs: Vec<Target>,
+    pub(crate) f: WitnessFn<F>,
+}
+
+impl<F: Field> Debug for ClosureGenerator<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ClosureGenerator")
+            .field("deps", &self.deps)
+            .field("outputs", &self.outputs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for ClosureGenerator<F> {
+    fn id(&self) -> String {
+        "ClosureGenerator".to_string()
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.deps.clone()
+    }
+
+    fn outputs(&self) -> Vec<Target> {
+        self.outputs.clone()
+    }
+
+    fn run_once(
+        &self,
+        witness: &PartitionWitness<F>,
+        out_buffer: &mut GeneratedValues<F>,
+    ) -> Result<()> {
+        let values = (self.f)(&witness.get_targets(&self.deps));
+        ensure!(
+            values.len() == self.outputs.len(),
+            "Closure returned {} values, but {} were expected.",
+            values.len(),
+            self.outputs.len()
+        );
+        out_buffer.set_target_arr(&self.outputs, &values)
+    }
+
+    fn serialize(
+        &self,
+        _dst: &mut Vec<u8>,
+        _common_data: &CommonCircuitData<F, D>,
+    ) -> IoResult<()> {
+        Err(IoError)
+    }
+
+    fn deserialize(_src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        Err(IoError)
+    }
+}
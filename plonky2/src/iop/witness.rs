@@ -301,6 +301,30 @@ impl<F: Field> PartialWitness<F> {
             target_values: HashMap::new(),
         }
     }
+
+    /// Imports all target assignments from `other`. Returns an error, leaving `self` unchanged, if
+    /// both witnesses assign different values to the same target.
+    pub fn try_merge(&mut self, other: PartialWitness<F>) -> Result<()> {
+        for (target, value) in &other.target_values {
+            if let Some(old_value) = self.target_values.get(target) {
+                if value != old_value {
+                    return Err(anyhow!(
+                        "Target {:?} was set twice with different values: {} != {}",
+                        target,
+                        old_value,
+                        value
+                    ));
+                }
+            }
+        }
+        self.target_values.extend(other.target_values);
+        Ok(())
+    }
+
+    /// Like `try_merge`, but panics if both witnesses assign different values to the same target.
+    pub fn merge(&mut self, other: PartialWitness<F>) {
+        self.try_merge(other).unwrap()
+    }
 }
 
 impl<F: Field> WitnessWrite<F> for PartialWitness<F> {
@@ -400,3 +424,68 @@ impl<F: Field> Witness<F> for PartitionWitness<'_, F> {
         self.values[rep_index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::goldilocks_field::GoldilocksField;
+
+    type F = GoldilocksField;
+
+    #[test]
+    fn test_merge_disjoint() -> Result<()> {
+        let mut pw = PartialWitness::<F>::new();
+        pw.set_target(Target::VirtualTarget { index: 0 }, F::ONE)?;
+        let mut other = PartialWitness::new();
+        other.set_target(Target::VirtualTarget { index: 1 }, F::TWO)?;
+
+        pw.merge(other);
+        assert_eq!(pw.target_values.len(), 2);
+        assert_eq!(pw.get_target(Target::VirtualTarget { index: 0 }), F::ONE);
+        assert_eq!(pw.get_target(Target::VirtualTarget { index: 1 }), F::TWO);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_consistent_overlap() -> Result<()> {
+        let t = Target::wire(0, 0);
+        let mut pw = PartialWitness::<F>::new();
+        pw.set_target(t, F::TWO)?;
+        let mut other = PartialWitness::new();
+        other.set_target(t, F::TWO)?;
+
+        pw.try_merge(other)?;
+        assert_eq!(pw.target_values.len(), 1);
+        assert_eq!(pw.get_target(t), F::TWO);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflicting_overlap() -> Result<()> {
+        let t = Target::wire(0, 0);
+        let u = Target::wire(0, 1);
+        let mut pw = PartialWitness::<F>::new();
+        pw.set_target(t, F::ONE)?;
+        let mut other = PartialWitness::new();
+        other.set_target(t, F::TWO)?;
+        other.set_target(u, F::TWO)?;
+
+        assert!(pw.try_merge(other).is_err());
+        // A failed merge leaves the witness untouched.
+        assert_eq!(pw.target_values.len(), 1);
+        assert_eq!(pw.get_target(t), F::ONE);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merge_conflicting_overlap_panics() {
+        let t = Target::wire(0, 0);
+        let mut pw = PartialWitness::<F>::new();
+        pw.set_target(t, F::ONE).unwrap();
+        let mut other = PartialWitness::new();
+        other.set_target(t, F::TWO).unwrap();
+
+        pw.merge(other);
+    }
+}
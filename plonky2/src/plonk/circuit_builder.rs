@@ -1,7 +1,7 @@
 //! Logic for building plonky2 circuits.
 
 #[cfg(not(feature = "std"))]
-use alloc::{collections::BTreeMap, sync::Arc, vec, vec::Vec};
+use alloc::{collections::BTreeMap, format, string::String, sync::Arc, vec, vec::Vec};
 use core::cmp::max;
 #[cfg(feature = "std")]
 use std::{collections::BTreeMap, sync::Arc};
@@ -36,7 +36,8 @@ use crate::hash::merkle_proofs::MerkleProofTarget;
 use crate::hash::merkle_tree::MerkleCap;
 use crate::iop::ext_target::ExtensionTarget;
 use crate::iop::generator::{
-    ConstantGenerator, CopyGenerator, RandomValueGenerator, SimpleGenerator, WitnessGeneratorRef,
+    find_generator_cycle, ClosureGenerator, ConstantGenerator, CopyGenerator, RandomValueGenerator,
+    SimpleGenerator, WitnessGeneratorRef,
 };
 use crate::iop::target::{BoolTarget, Target};
 use crate::iop::wire::Wire;
@@ -192,6 +193,9 @@ pub struct CircuitBuilder<F: RichField + Extendable<D>, const D: usize> {
     // Lookup tables in the form of `Vec<(input_value, output_value)>`.
     luts: Vec<LookupTable>,
 
+    /// For each bit width used in `range_check_via_lut`, the index of the `[0, 2^n_log)` LUT.
+    pub(crate) range_check_luts: HashMap<usize, usize>,
+
     /// Optional common data. When it is `Some(goal_data)`, the `build` function panics if the resulting
     /// common data doesn't equal `goal_data`.
     /// This is used in cyclic recursion.
@@ -200,6 +204,10 @@ pub struct CircuitBuilder<F: RichField + Extendable<D>, const D: usize> {
     /// Optional verifier data that is registered as public inputs.
     /// This is used in cyclic recursion to hold the circuit's own verifier key.
     pub(crate) verifier_data_public_input: Option<VerifierCircuitTarget>,
+
+    /// Debug labels attached to gate rows with `add_gate_labeled`.
+    #[cfg(feature = "gate_labels")]
+    gate_labels: HashMap<usize, String>,
 }
 
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
@@ -227,8 +235,11 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
             lookup_rows: Vec::new(),
             lut_to_lookups: Vec::new(),
             luts: Vec::new(),
+            range_check_luts: HashMap::new(),
             goal_common_data: None,
             verifier_data_public_input: None,
+            #[cfg(feature = "gate_labels")]
+            gate_labels: HashMap::new(),
         };
         builder.check_config();
         builder
@@ -279,6 +290,20 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         targets.iter().for_each(|&t| self.register_public_input(t));
     }
 
+    /// Hashes the given targets with `H` and registers only the resulting digest as public inputs,
+    /// returning the digest.
+    ///
+    /// Note that the hashed values themselves are not exposed: a verifier must recompute their
+    /// hash off-circuit, with the same hasher, and check it against the proof's public inputs.
+    pub fn register_public_input_hash<H: AlgebraicHasher<F>>(
+        &mut self,
+        targets: &[Target],
+    ) -> HashOutTarget {
+        let hash = self.hash_n_to_hash_no_pad::<H>(targets.to_vec());
+        self.register_public_inputs(&hash.elements);
+        hash
+    }
+
     /// Outputs the number of public inputs in this circuit.
     pub fn num_public_inputs(&self) -> usize {
         self.public_inputs.len()
@@ -402,6 +427,19 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         b
     }
 
+    /// Constrains each of `targets` to be boolean, i.e. `b * (b - 1) = 0`, and returns them as
+    /// [`BoolTarget`]s.
+    pub fn assert_bool_array(&mut self, targets: &[Target]) -> Vec<BoolTarget> {
+        targets
+            .iter()
+            .map(|&t| {
+                let b = BoolTarget::new_unsafe(t);
+                self.assert_bool(b);
+                b
+            })
+            .collect()
+    }
+
     /// Add a virtual target and register it as a public input.
     pub fn add_virtual_public_input(&mut self) -> Target {
         let t = self.add_virtual_target();
@@ -409,6 +447,13 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         t
     }
 
+    /// Add `n` virtual targets and register them as public inputs, in order.
+    pub fn add_virtual_public_inputs(&mut self, n: usize) -> Vec<Target> {
+        let ts = self.add_virtual_targets(n);
+        self.register_public_inputs(&ts);
+        ts
+    }
+
     pub fn add_virtual_public_input_arr<const N: usize>(&mut self) -> [Target; N] {
         let ts = [0; N].map(|_| self.add_virtual_target());
         self.register_public_inputs(&ts);
@@ -481,6 +526,33 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         row
     }
 
+    /// Like `add_gate`, but also attaches a debug label to the gate's row, which can be retrieved
+    /// with `gate_label`. Labels are only recorded when the `gate_labels` feature is enabled.
+    pub fn add_gate_labeled<G: Gate<F, D>>(
+        &mut self,
+        gate_type: G,
+        constants: Vec<F>,
+        label: &str,
+    ) -> usize {
+        let row = self.add_gate(gate_type, constants);
+        #[cfg(feature = "gate_labels")]
+        self.gate_labels.insert(row, String::from(label));
+        #[cfg(not(feature = "gate_labels"))]
+        let _ = label;
+        row
+    }
+
+    /// Returns the debug label attached to the gate at `row`, if any.
+    pub fn gate_label(&self, row: usize) -> Option<&str> {
+        #[cfg(feature = "gate_labels")]
+        return self.gate_labels.get(&row).map(String::as_str);
+        #[cfg(not(feature = "gate_labels"))]
+        {
+            let _ = row;
+            None
+        }
+    }
+
     fn check_gate_compatibility<G: Gate<F, D>>(&self, gate: &G) {
         assert!(
             gate.num_wires() <= self.config.num_wires,
@@ -533,6 +605,41 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         }
     }
 
+    /// Enforces that the underlying values of two [`Target`] slices are equal.
+    ///
+    /// Panics if the slices have different lengths, since connecting only their common prefix
+    /// would silently leave the remaining targets unconstrained.
+    pub fn connect_slices(&mut self, x: &[Target], y: &[Target]) {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Tried to connect slices of different lengths ({} and {})",
+            x.len(),
+            y.len()
+        );
+        for (&a, &b) in x.iter().zip(y) {
+            self.connect(a, b);
+        }
+    }
+
+    /// Enforces that each target in `targets` equals the corresponding constant in `values`.
+    ///
+    /// Constants are deduplicated and packed `num_constants` to a row of [`ConstantGate`] at build
+    /// time, so pinning `n` distinct values costs about `n / num_constants` gates.
+    pub fn connect_constants(&mut self, targets: &[Target], values: &[F]) {
+        assert_eq!(
+            targets.len(),
+            values.len(),
+            "Tried to connect {} targets to {} constants",
+            targets.len(),
+            values.len()
+        );
+        for (&t, &v) in targets.iter().zip(values) {
+            let c = self.constant(v);
+            self.connect(t, c);
+        }
+    }
+
     /// Enforces that two [`ExtensionTarget<D>`] underlying values are equal.
     pub fn connect_extension(&mut self, src: ExtensionTarget<D>, dst: ExtensionTarget<D>) {
         for i in 0..D {
@@ -595,6 +702,22 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
             .push(WitnessGeneratorRef::new(generator.adapter()));
     }
 
+    /// Adds a generator which sets `outputs` to the result of calling `f` on the values of `deps`.
+    /// The outputs are not constrained in any way, so callers must add the constraints they rely
+    /// on. Circuits using such generators can't be serialized; see `ClosureGenerator`.
+    pub fn add_simple_generator_fn(
+        &mut self,
+        deps: Vec<Target>,
+        outputs: Vec<Target>,
+        f: impl Fn(&[F]) -> Vec<F> + Send + Sync + 'static,
+    ) {
+        self.add_simple_generator(ClosureGenerator {
+            deps,
+            outputs,
+            f: Arc::new(f),
+        });
+    }
+
     /// Returns a routable target with a value of 0.
     pub fn zero(&mut self) -> Target {
         self.constant(F::ZERO)
@@ -713,6 +836,13 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         self.context_log.pop(self.num_gates());
     }
 
+    /// Returns the number of gates added within each context opened with `push_context`, named by
+    /// the `" > "`-separated path of enclosing contexts. Contexts which are still open are counted
+    /// up to the current gate count.
+    pub fn context_gate_counts(&self) -> Vec<(String, usize)> {
+        self.context_log.gate_counts(self.num_gates())
+    }
+
     /// Returns the total number of LUTs.
     pub fn get_luts_length(&self) -> usize {
         self.luts.len()
@@ -1233,6 +1363,22 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
             indices.shrink_to_fit();
         }
 
+        // Generators which wait on each other would stall witness generation, leaving some targets
+        // unset, so we reject them here.
+        if let Some(cycle) =
+            find_generator_cycle(&self.generators, &generator_indices_by_watches, |t| {
+                forest.parents[forest.target_index(t)]
+            })
+        {
+            let description = cycle
+                .iter()
+                .chain(cycle.first())
+                .map(|&i| format!("{} #{}", self.generators[i].0.id(), i))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            panic!("Witness generators have a dependency cycle: {description}");
+        }
+
         let num_gate_constraints = gates
             .iter()
             .map(|gate| gate.0.num_constraints())
@@ -1346,3 +1492,283 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
         circuit_data.verifier_data()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::types::Sample;
+    use crate::hash::hashing::hash_n_to_hash_no_pad;
+    use crate::hash::poseidon::{PoseidonHash, PoseidonPermutation};
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
+    use crate::plonk::circuit_data::CircuitConfig;
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+    use crate::with_context;
+
+    #[test]
+    fn test_context_gate_counts() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        builder.add_gate(NoopGate, vec![]);
+        with_context!(builder, "outer", {
+            builder.add_gate(NoopGate, vec![]);
+            with_context!(builder, "inner", {
+                builder.add_gate(NoopGate, vec![]);
+                builder.add_gate(NoopGate, vec![]);
+            });
+        });
+        with_context!(builder, "other", {
+            builder.add_gate(NoopGate, vec![]);
+        });
+
+        assert_eq!(
+            builder.context_gate_counts(),
+            vec![
+                ("outer".to_string(), 3),
+                ("outer > inner".to_string(), 2),
+                ("other".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_virtual_public_inputs() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let first = builder.add_virtual_public_input();
+        let vec = builder.add_virtual_public_inputs(3);
+        let arr = builder.add_virtual_public_input_arr::<2>();
+
+        let expected = [vec![first], vec, arr.to_vec()].concat();
+        assert_eq!(builder.public_inputs, expected);
+    }
+
+    #[test]
+    fn test_connect_slices() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let x = builder.add_virtual_targets(5);
+        let y = builder.add_virtual_targets(5);
+        builder.connect_slices(&x, &y);
+
+        let values = F::rand_vec(5);
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&x, &values)?;
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to connect slices of different lengths (3 and 4)")]
+    fn test_connect_slices_length_mismatch() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let x = builder.add_virtual_targets(3);
+        let y = builder.add_virtual_targets(4);
+        builder.connect_slices(&x, &y);
+    }
+
+    #[test]
+    fn test_assert_bool_array() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let prove_with = |values: &[F]| {
+            let mut builder =
+                CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+            let targets = builder.add_virtual_targets(values.len());
+            let bits = builder.assert_bool_array(&targets);
+            assert_eq!(bits.iter().map(|b| b.target).collect::<Vec<_>>(), targets);
+
+            let mut pw = PartialWitness::new();
+            pw.set_target_arr(&targets, values)?;
+            let data = builder.build::<C>();
+            let proof = data.prove(pw)?;
+            data.verify(proof)
+        };
+
+        prove_with(&[F::ZERO, F::ONE, F::ONE, F::ZERO])?;
+        assert!(prove_with(&[F::ZERO, F::TWO, F::ONE, F::ZERO]).is_err());
+        assert!(prove_with(&[F::NEG_ONE]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_connect_constants() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let values = F::rand_vec(16);
+        let prove_with = |assignment: &[F]| {
+            let mut builder =
+                CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+            let targets = builder.add_virtual_targets(16);
+            builder.connect_constants(&targets, &values);
+
+            let mut pw = PartialWitness::new();
+            pw.set_target_arr(&targets, assignment)?;
+            let data = builder.build::<C>();
+            let proof = data.prove(pw)?;
+            data.verify(proof)
+        };
+
+        prove_with(&values)?;
+        let mut wrong_values = values.clone();
+        wrong_values[7] += F::ONE;
+        assert!(prove_with(&wrong_values).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_connect_constants_gate_count() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let values = F::rand_vec(16);
+        // The number of gates once `build` has placed the constant gates.
+        let num_gates = |naive: bool| {
+            let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+            let targets = builder.add_virtual_targets(16);
+            if naive {
+                for (&t, &v) in targets.iter().zip(&values) {
+                    let c = builder.constant(v);
+                    builder.connect(t, c);
+                }
+            } else {
+                builder.connect_constants(&targets, &values);
+            }
+            let missing = builder.constants_to_targets.len() - builder.constant_generators.len();
+            builder.num_gates() + missing.div_ceil(config.num_constants)
+        };
+
+        assert_eq!(num_gates(false), 16 / config.num_constants);
+        assert_eq!(num_gates(false), num_gates(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to connect 3 targets to 2 constants")]
+    fn test_connect_constants_length_mismatch() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_targets(3);
+        builder.connect_constants(&targets, &[F::ONE, F::TWO]);
+    }
+
+    #[test]
+    fn test_add_simple_generator_fn() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let x = builder.add_virtual_target();
+        let x_inv = builder.add_virtual_target();
+        builder.add_simple_generator_fn(vec![x], vec![x_inv], |values| vec![values[0].inverse()]);
+        let product = builder.mul(x, x_inv);
+        builder.assert_one(product);
+        builder.register_public_input(x_inv);
+
+        let x_value = F::rand();
+        let mut pw = PartialWitness::new();
+        pw.set_target(x, x_value)?;
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        assert_eq!(proof.public_inputs, vec![x_value.inverse()]);
+        data.verify(proof)
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Witness generators have a dependency cycle: ClosureGenerator #0 -> ClosureGenerator #1 -> ClosureGenerator #0"
+    )]
+    fn test_generator_dependency_cycle() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let x = builder.add_virtual_target();
+        let y = builder.add_virtual_target();
+        builder.add_simple_generator_fn(vec![x], vec![y], |values| vec![values[0].double()]);
+        builder.add_simple_generator_fn(vec![y], vec![x], |values| vec![values[0]]);
+        builder.register_public_input(y);
+        builder.build::<C>();
+    }
+
+    #[test]
+    #[cfg(feature = "gate_labels")]
+    fn test_add_gate_labeled() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let unlabeled_row = builder.add_gate(NoopGate, vec![]);
+        let first_row = builder.add_gate_labeled(NoopGate, vec![], "first");
+        let second_row = builder.add_gate_labeled(NoopGate, vec![], "second");
+
+        assert_eq!(builder.gate_label(unlabeled_row), None);
+        assert_eq!(builder.gate_label(first_row), Some("first"));
+        assert_eq!(builder.gate_label(second_row), Some("second"));
+        assert_eq!(builder.gate_label(second_row + 1), None);
+    }
+
+    #[test]
+    fn test_register_public_input_hash() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let num_values = 100;
+        let values = F::rand_vec(num_values);
+
+        // Commit to the values through a single digest.
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_targets(num_values);
+        builder.register_public_input_hash::<PoseidonHash>(&targets);
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&targets, &values)?;
+        let data = builder.build::<C>();
+        let hashed_proof = data.prove(pw)?;
+        data.verify(hashed_proof.clone())?;
+
+        let expected_hash = hash_n_to_hash_no_pad::<F, PoseidonPermutation<F>>(&values);
+        assert_eq!(hashed_proof.public_inputs, expected_hash.elements);
+
+        // Register all the values directly.
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let targets = builder.add_virtual_targets(num_values);
+        builder.register_public_inputs(&targets);
+        let mut pw = PartialWitness::new();
+        pw.set_target_arr(&targets, &values)?;
+        let data = builder.build::<C>();
+        let direct_proof = data.prove(pw)?;
+        data.verify(direct_proof.clone())?;
+
+        assert_eq!(direct_proof.public_inputs, values);
+        assert!(hashed_proof.to_bytes().len() < direct_proof.to_bytes().len());
+
+        Ok(())
+    }
+}
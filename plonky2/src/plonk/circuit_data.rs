@@ -19,13 +19,14 @@ use core::ops::{Range, RangeFrom};
 use std::collections::BTreeMap;
 
 use anyhow::Result;
+use rand::RngCore;
 use serde::Serialize;
 
 use super::circuit_builder::LookupWire;
 use crate::field::extension::Extendable;
 use crate::field::fft::FftRootTable;
 use crate::field::types::Field;
-use crate::fri::oracle::PolynomialBatch;
+use crate::fri::oracle::{PolynomialBatch, SALT_SIZE};
 use crate::fri::reduction_strategies::FriReductionStrategy;
 use crate::fri::structure::{
     FriBatchInfo, FriBatchInfoTarget, FriInstanceInfo, FriInstanceInfoTarget, FriOracleInfo,
@@ -36,17 +37,18 @@ use crate::gates::gate::GateRef;
 use crate::gates::lookup::Lookup;
 use crate::gates::lookup_table::LookupTable;
 use crate::gates::selectors::SelectorsInfo;
-use crate::hash::hash_types::{HashOutTarget, MerkleCapTarget, RichField};
+use crate::hash::hash_types::{HashOutTarget, MerkleCapTarget, RichField, NUM_HASH_OUT_ELTS};
 use crate::hash::merkle_tree::MerkleCap;
+use crate::hash::poseidon::SPONGE_RATE;
 use crate::iop::ext_target::ExtensionTarget;
-use crate::iop::generator::{generate_partial_witness, WitnessGeneratorRef};
+use crate::iop::generator::{generate_partial_witness, GeneratorTimings, WitnessGeneratorRef};
 use crate::iop::target::Target;
 use crate::iop::witness::{PartialWitness, PartitionWitness};
 use crate::plonk::circuit_builder::CircuitBuilder;
 use crate::plonk::config::{GenericConfig, Hasher};
 use crate::plonk::plonk_common::PlonkOracle;
 use crate::plonk::proof::{CompressedProofWithPublicInputs, ProofWithPublicInputs};
-use crate::plonk::prover::prove;
+use crate::plonk::prover::{prove, prove_with_options, prove_with_rng, ProveOptions};
 use crate::plonk::verifier::verify;
 use crate::util::serialization::{
     Buffer, GateSerializer, IoResult, Read, WitnessGeneratorSerializer, Write,
@@ -195,6 +197,35 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
         )
     }
 
+    pub fn prove_with_options(
+        &self,
+        inputs: PartialWitness<F>,
+        options: &ProveOptions,
+    ) -> Result<(ProofWithPublicInputs<F, C, D>, GeneratorTimings)> {
+        prove_with_options::<F, C, D>(
+            &self.prover_only,
+            &self.common,
+            inputs,
+            options,
+            &mut TimingTree::default(),
+        )
+    }
+
+    /// Same as `prove`, but draws all of the prover's randomness from `rng`.
+    pub fn prove_with_rng<R: RngCore>(
+        &self,
+        inputs: PartialWitness<F>,
+        rng: &mut R,
+    ) -> Result<ProofWithPublicInputs<F, C, D>> {
+        prove_with_rng::<F, C, R, D>(
+            &self.prover_only,
+            &self.common,
+            inputs,
+            rng,
+            &mut TimingTree::default(),
+        )
+    }
+
     pub fn verify(&self, proof_with_pis: ProofWithPublicInputs<F, C, D>) -> Result<()> {
         verify::<F, C, D>(proof_with_pis, &self.verifier_only, &self.common)
     }
@@ -292,6 +323,35 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
             &mut TimingTree::default(),
         )
     }
+
+    pub fn prove_with_options(
+        &self,
+        inputs: PartialWitness<F>,
+        options: &ProveOptions,
+    ) -> Result<(ProofWithPublicInputs<F, C, D>, GeneratorTimings)> {
+        prove_with_options::<F, C, D>(
+            &self.prover_only,
+            &self.common,
+            inputs,
+            options,
+            &mut TimingTree::default(),
+        )
+    }
+
+    /// Same as `prove`, but draws all of the prover's randomness from `rng`.
+    pub fn prove_with_rng<R: RngCore>(
+        &self,
+        inputs: PartialWitness<F>,
+        rng: &mut R,
+    ) -> Result<ProofWithPublicInputs<F, C, D>> {
+        prove_with_rng::<F, C, R, D>(
+            &self.prover_only,
+            &self.common,
+            inputs,
+            rng,
+            &mut TimingTree::default(),
+        )
+    }
 }
 
 /// Circuit data required by the prover.
@@ -496,6 +556,60 @@ impl<F: RichField + Extendable<D>, const D: usize> CommonCircuitData<F, D> {
         self.quotient_degree_factor * self.degree()
     }
 
+    /// Estimates the number of gates needed to verify proofs of this circuit recursively, e.g. to
+    /// decide whether to shrink the FRI parameters before recursing. The estimate assumes a
+    /// Poseidon-based configuration verified with the standard recursion config, and is meant to
+    /// be accurate to within about 10%.
+    pub fn recursive_verifier_gate_estimate(&self) -> usize {
+        // Permutations needed to hash `n` elements in a Merkle leaf, each taking one gate.
+        let hash_gates = |n: usize| {
+            if n <= NUM_HASH_OUT_ELTS {
+                0
+            } else {
+                n.div_ceil(SPONGE_RATE)
+            }
+        };
+        let lde_bits = self.degree_bits() + self.config.fri_config.rate_bits;
+        let cap_height = self.config.fri_config.cap_height;
+
+        // Each query round opens every oracle against its Merkle cap, then checks each FRI layer
+        // with another Merkle proof and an interpolation gate.
+        let initial_gates: usize = self
+            .fri_oracles()
+            .iter()
+            .map(|oracle| {
+                let salt_size = if oracle.blinding && self.fri_params.hiding {
+                    SALT_SIZE
+                } else {
+                    0
+                };
+                hash_gates(oracle.num_polys + salt_size) + lde_bits - cap_height
+            })
+            .sum();
+        let mut layer_bits = lde_bits;
+        let layer_gates: usize = self
+            .fri_params
+            .reduction_arity_bits
+            .iter()
+            .map(|&arity_bits| {
+                layer_bits -= arity_bits;
+                hash_gates(D << arity_bits) + layer_bits.saturating_sub(cap_height) + 1
+            })
+            .sum();
+        // Index decomposition, cap selection and combining the initial openings.
+        const OTHER_GATES_PER_QUERY: usize = 24;
+        let query_gates = initial_gates + layer_gates + OTHER_GATES_PER_QUERY;
+
+        // Evaluating the gate constraints at `zeta`, measured at roughly 2 gates per 3 constraints.
+        let num_constraints: usize = self.gates.iter().map(|g| g.0.num_constraints()).sum();
+        let constraint_gates = num_constraints * 2 / 3;
+
+        // The challenger, proof of work and final polynomial checks.
+        const FIXED_GATES: usize = 100;
+
+        self.config.fri_config.num_query_rounds * query_gates + constraint_gates + FIXED_GATES
+    }
+
     /// Range of the constants polynomials in the `constants_sigmas_commitment`.
     pub const fn constants_range(&self) -> Range<usize> {
         0..self.num_constants
@@ -676,3 +790,119 @@ pub struct VerifierCircuitTarget {
     /// seed Fiat-Shamir.
     pub circuit_digest: HashOutTarget,
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::gates::noop::NoopGate;
+    use crate::iop::witness::WitnessWrite;
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+    use crate::util::serialization::DefaultGateSerializer;
+
+    #[test]
+    fn test_prove_with_rng() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_zk_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let x = builder.add_virtual_public_input();
+        let y = builder.exp_u64(x, 17);
+        builder.register_public_input(y);
+        let data = builder.build::<C>();
+
+        let prove = |seed| {
+            let mut pw = PartialWitness::new();
+            pw.set_target(x, F::TWO)?;
+            data.prove_with_rng(pw, &mut ChaCha8Rng::seed_from_u64(seed))
+        };
+
+        let proof = prove(0)?;
+        assert_eq!(prove(0)?, proof);
+        let other_proof = prove(1)?;
+        assert_ne!(other_proof, proof);
+        assert_eq!(other_proof.public_inputs, proof.public_inputs);
+
+        data.verify(proof)?;
+        data.verify(other_proof)
+    }
+
+    #[test]
+    fn test_recursive_verifier_gate_estimate() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let verifier_data = |common_data: &CommonCircuitData<F, D>| {
+            let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+            let proof = builder.add_virtual_proof_with_pis(common_data);
+            let inner_data = builder.add_virtual_verifier_data(config.fri_config.cap_height);
+            builder.verify_proof::<C>(&proof, &inner_data, common_data);
+            let num_gates = builder.num_gates();
+            (num_gates, builder.build::<C>())
+        };
+        let assert_close = |common_data: &CommonCircuitData<F, D>, num_gates: usize| {
+            let estimate = common_data.recursive_verifier_gate_estimate();
+            assert!(
+                estimate.abs_diff(num_gates) * 10 <= num_gates,
+                "estimated {estimate} gates, but the verifier has {num_gates}"
+            );
+        };
+
+        // A large circuit with few gate types.
+        let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+        for _ in 0..16_000 {
+            builder.add_gate(NoopGate, vec![]);
+        }
+        let inner = builder.build::<C>();
+        let (num_gates, verifier) = verifier_data(&inner.common);
+        assert_close(&inner.common, num_gates);
+
+        // A recursive verifier, with many gate types.
+        let (num_gates, _) = verifier_data(&verifier.common);
+        assert_close(&verifier.common, num_gates);
+    }
+
+    #[test]
+    fn test_verifier_data_serialization() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let x = builder.add_virtual_public_input();
+        let y = builder.exp_u64(x, 17);
+        builder.register_public_input(y);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(x, F::TWO)?;
+        let proof = data.prove(pw)?;
+
+        let verifier_only_bytes = data.verifier_only.to_bytes().map_err(anyhow::Error::msg)?;
+        let verifier_only = VerifierOnlyCircuitData::<C, D>::from_bytes(verifier_only_bytes)
+            .map_err(anyhow::Error::msg)?;
+        assert_eq!(verifier_only, data.verifier_only);
+
+        let common_bytes = data
+            .common
+            .to_bytes(&DefaultGateSerializer)
+            .map_err(anyhow::Error::msg)?;
+        let common = CommonCircuitData::<F, D>::from_bytes(common_bytes, &DefaultGateSerializer)
+            .map_err(anyhow::Error::msg)?;
+        assert_eq!(common, data.common);
+
+        let verifier_data = VerifierCircuitData {
+            verifier_only,
+            common,
+        };
+        verifier_data.verify(proof)
+    }
+}
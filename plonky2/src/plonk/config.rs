@@ -47,15 +47,21 @@ pub trait Hasher<F: RichField>: Sized + Copy + Debug + Eq + PartialEq {
     /// However, it is still collision-resistant in cases where the input has a fixed length.
     fn hash_no_pad(input: &[F]) -> Self::Hash;
 
-    /// Pad the message using the `pad10*1` rule, then hash it.
-    fn hash_pad(input: &[F]) -> Self::Hash {
+    /// Pad the message using the `pad10*1` rule: append a one, then zeros, then a final one, so
+    /// that the padded length is a multiple of the permutation rate.
+    fn pad_input(input: &[F]) -> Vec<F> {
         let mut padded_input = input.to_vec();
         padded_input.push(F::ONE);
         while (padded_input.len() + 1) % Self::Permutation::RATE != 0 {
             padded_input.push(F::ZERO);
         }
         padded_input.push(F::ONE);
-        Self::hash_no_pad(&padded_input)
+        padded_input
+    }
+
+    /// Pad the message using the `pad10*1` rule (see [`Hasher::pad_input`]), then hash it.
+    fn hash_pad(input: &[F]) -> Self::Hash {
+        Self::hash_no_pad(&Self::pad_input(input))
     }
 
     /// Hash the slice if necessary to reduce its length to ~256 bits. If it already fits, this is a
@@ -73,6 +79,9 @@ pub trait Hasher<F: RichField>: Sized + Copy + Debug + Eq + PartialEq {
         }
     }
 
+    /// Compresses two child digests into their parent digest. This is the exact compression
+    /// function used by [`MerkleTree`](crate::hash::merkle_tree::MerkleTree), so external Merkle
+    /// code can use it to reproduce roots and caps.
     fn two_to_one(left: Self::Hash, right: Self::Hash) -> Self::Hash;
 }
 
@@ -124,3 +133,36 @@ impl GenericConfig<2> for KeccakGoldilocksConfig {
     type Hasher = KeccakHash<25>;
     type InnerHasher = PoseidonHash;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::types::Sample;
+
+    fn check_pad_input<H: Hasher<GoldilocksField>>() {
+        type F = GoldilocksField;
+        let rate = H::Permutation::RATE;
+        for len in [
+            0,
+            1,
+            rate - 2,
+            rate - 1,
+            rate,
+            rate + 1,
+            2 * rate,
+            3 * rate - 1,
+        ] {
+            let input = F::rand_vec(len);
+            let padded = H::pad_input(&input);
+            assert_eq!(padded.len() % rate, 0);
+            assert_eq!(&padded[..len], &input[..]);
+            assert_eq!(H::hash_no_pad(&padded), H::hash_pad(&input));
+        }
+    }
+
+    #[test]
+    fn test_pad_input() {
+        check_pad_input::<PoseidonHash>();
+        check_pad_input::<KeccakHash<25>>();
+    }
+}
@@ -26,7 +26,7 @@ use crate::iop::ext_target::ExtensionTarget;
 use crate::iop::target::Target;
 use crate::plonk::circuit_data::{CommonCircuitData, VerifierOnlyCircuitData};
 use crate::plonk::config::{GenericConfig, Hasher};
-use crate::plonk::verifier::verify_with_challenges;
+use crate::plonk::verifier::{verify, verify_with_challenges};
 use crate::util::serialization::{Buffer, Read, Write};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -74,6 +74,11 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize> P
     }
 }
 
+/// A type which can be parsed from the public inputs of a proof.
+pub trait FromPublicInputs<F>: Sized {
+    fn from_public_inputs(public_inputs: &[F]) -> anyhow::Result<Self>;
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(bound = "")]
 pub struct ProofWithPublicInputs<
@@ -107,6 +112,18 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
         C::InnerHasher::hash_no_pad(&self.public_inputs)
     }
 
+    /// Verifies the proof, then parses its public inputs as a `T`. The public inputs are only
+    /// parsed if the proof is valid.
+    pub fn verify_and_extract<T: FromPublicInputs<F>>(
+        self,
+        verifier_data: &VerifierOnlyCircuitData<C, D>,
+        common_data: &CommonCircuitData<F, D>,
+    ) -> anyhow::Result<T> {
+        let public_inputs = self.public_inputs.clone();
+        verify(self, verifier_data, common_data)?;
+        T::from_public_inputs(&public_inputs)
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut buffer = Vec::new();
         buffer
@@ -166,7 +183,11 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
             plonk_zs_partial_products_cap,
             quotient_polys_cap,
             openings,
-            opening_proof: opening_proof.decompress(challenges, fri_inferred_elements, params),
+            opening_proof: opening_proof.decompress(
+                &challenges.fri_challenges,
+                fri_inferred_elements,
+                params,
+            ),
         }
     }
 }
@@ -286,9 +307,8 @@ pub(crate) struct ProofChallengesTarget<const D: usize> {
 }
 
 /// Coset elements that can be inferred in the FRI reduction steps.
-pub(crate) struct FriInferredElements<F: RichField + Extendable<D>, const D: usize>(
-    pub Vec<F::Extension>,
-);
+#[derive(Debug)]
+pub struct FriInferredElements<F: RichField + Extendable<D>, const D: usize>(pub Vec<F::Extension>);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProofWithPublicInputsTarget<const D: usize> {
@@ -459,18 +479,80 @@ mod tests {
 
     use anyhow::Result;
     use itertools::Itertools;
-    use plonky2_field::types::Sample;
+    use plonky2_field::types::{Field, Sample};
 
     use super::*;
     use crate::fri::reduction_strategies::FriReductionStrategy;
     use crate::gates::lookup_table::LookupTable;
     use crate::gates::noop::NoopGate;
-    use crate::iop::witness::PartialWitness;
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
     use crate::plonk::circuit_builder::CircuitBuilder;
     use crate::plonk::circuit_data::CircuitConfig;
     use crate::plonk::config::PoseidonGoldilocksConfig;
     use crate::plonk::verifier::verify;
 
+    /// The public inputs of a circuit computing the `n`-th Fibonacci number.
+    #[derive(Debug, PartialEq, Eq)]
+    struct FibonacciPublicInputs<F> {
+        first: F,
+        second: F,
+        result: F,
+    }
+
+    impl<F: Copy> FromPublicInputs<F> for FibonacciPublicInputs<F> {
+        fn from_public_inputs(public_inputs: &[F]) -> Result<Self> {
+            ensure!(public_inputs.len() == 3, "Expected 3 public inputs.");
+            Ok(Self {
+                first: public_inputs[0],
+                second: public_inputs[1],
+                result: public_inputs[2],
+            })
+        }
+    }
+
+    #[test]
+    fn test_verify_and_extract() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let [first, second] = builder.add_virtual_public_input_arr();
+        let mut prev = first;
+        let mut cur = second;
+        for _ in 0..10 {
+            let next = builder.add(prev, cur);
+            prev = cur;
+            cur = next;
+        }
+        builder.register_public_input(cur);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(first, F::ZERO)?;
+        pw.set_target(second, F::ONE)?;
+        let proof = data.prove(pw)?;
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof.public_inputs[2] += F::ONE;
+        assert!(tampered_proof
+            .verify_and_extract::<FibonacciPublicInputs<F>>(&data.verifier_only, &data.common)
+            .is_err());
+
+        let public_inputs: FibonacciPublicInputs<F> =
+            proof.verify_and_extract(&data.verifier_only, &data.common)?;
+        assert_eq!(
+            public_inputs,
+            FibonacciPublicInputs {
+                first: F::ZERO,
+                second: F::ONE,
+                result: F::from_canonical_u64(89),
+            }
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_proof_compression() -> Result<()> {
         const D: usize = 2;
@@ -8,6 +8,7 @@ use core::mem::swap;
 use anyhow::{ensure, Result};
 use hashbrown::HashMap;
 use plonky2_maybe_rayon::*;
+use rand::RngCore;
 
 use super::circuit_builder::{LookupChallenges, LookupWire};
 use crate::field::extension::Extendable;
@@ -20,7 +21,10 @@ use crate::gates::lookup_table::LookupTableGate;
 use crate::gates::selectors::LookupSelectors;
 use crate::hash::hash_types::RichField;
 use crate::iop::challenger::Challenger;
-use crate::iop::generator::generate_partial_witness;
+use crate::iop::generator::{
+    generate_partial_witness, generate_partial_witness_with_rng,
+    generate_partial_witness_with_timings, GeneratorTimings,
+};
 use crate::iop::target::Target;
 use crate::iop::witness::{MatrixWitness, PartialWitness, PartitionWitness, Witness, WitnessWrite};
 use crate::plonk::circuit_builder::NUM_COINS_LOOKUP;
@@ -130,15 +134,110 @@ where
     prove_with_partition_witness(prover_data, common_data, partition_witness, timing)
 }
 
+/// Options controlling optional behaviour of the prover.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ProveOptions {
+    /// Whether to record the wall-clock time spent in each type of witness generator. Timings are
+    /// only recorded if the `timing` feature is enabled.
+    pub profile_generators: bool,
+}
+
+/// Same as `prove`, but also returns the time spent running each type of generator, keyed by
+/// generator id, if `options.profile_generators` is set.
+pub fn prove_with_options<
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
+>(
+    prover_data: &ProverOnlyCircuitData<F, C, D>,
+    common_data: &CommonCircuitData<F, D>,
+    inputs: PartialWitness<F>,
+    options: &ProveOptions,
+    timing: &mut TimingTree,
+) -> Result<(ProofWithPublicInputs<F, C, D>, GeneratorTimings)>
+where
+    C::Hasher: Hasher<F>,
+    C::InnerHasher: Hasher<F>,
+{
+    let (partition_witness, generator_timings) = timed!(
+        timing,
+        &format!("run {} generators", prover_data.generators.len()),
+        if options.profile_generators {
+            generate_partial_witness_with_timings(inputs, prover_data, common_data)?
+        } else {
+            (
+                generate_partial_witness(inputs, prover_data, common_data)?,
+                GeneratorTimings::new(),
+            )
+        }
+    );
+
+    let proof = prove_with_partition_witness(prover_data, common_data, partition_witness, timing)?;
+    Ok((proof, generator_timings))
+}
+
+/// Same as `prove`, but draws all of the prover's randomness, i.e. the random witness values and
+/// the blinding salts of the FRI commitments, from `rng`. Proofs generated from the same seed are
+/// identical, which is useful for test vectors, while still being zero-knowledge if the circuit
+/// config enables it.
+pub fn prove_with_rng<
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    R: RngCore,
+    const D: usize,
+>(
+    prover_data: &ProverOnlyCircuitData<F, C, D>,
+    common_data: &CommonCircuitData<F, D>,
+    inputs: PartialWitness<F>,
+    rng: &mut R,
+    timing: &mut TimingTree,
+) -> Result<ProofWithPublicInputs<F, C, D>>
+where
+    C::Hasher: Hasher<F>,
+    C::InnerHasher: Hasher<F>,
+{
+    let partition_witness = timed!(
+        timing,
+        &format!("run {} generators", prover_data.generators.len()),
+        generate_partial_witness_with_rng(inputs, prover_data, common_data, rng)?
+    );
+
+    prove_with_partition_witness_helper(
+        prover_data,
+        common_data,
+        partition_witness,
+        timing,
+        Some(rng),
+    )
+}
+
 pub fn prove_with_partition_witness<
     F: RichField + Extendable<D>,
     C: GenericConfig<D, F = F>,
     const D: usize,
+>(
+    prover_data: &ProverOnlyCircuitData<F, C, D>,
+    common_data: &CommonCircuitData<F, D>,
+    partition_witness: PartitionWitness<F>,
+    timing: &mut TimingTree,
+) -> Result<ProofWithPublicInputs<F, C, D>>
+where
+    C::Hasher: Hasher<F>,
+    C::InnerHasher: Hasher<F>,
+{
+    prove_with_partition_witness_helper(prover_data, common_data, partition_witness, timing, None)
+}
+
+fn prove_with_partition_witness_helper<
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
 >(
     prover_data: &ProverOnlyCircuitData<F, C, D>,
     common_data: &CommonCircuitData<F, D>,
     mut partition_witness: PartitionWitness<F>,
     timing: &mut TimingTree,
+    mut rng: Option<&mut dyn RngCore>,
 ) -> Result<ProofWithPublicInputs<F, C, D>>
 where
     C::Hasher: Hasher<F>,
@@ -174,13 +273,14 @@ where
     let wires_commitment = timed!(
         timing,
         "compute wires commitment",
-        PolynomialBatch::<F, C, D>::from_values(
+        PolynomialBatch::<F, C, D>::from_values_helper(
             wires_values,
             config.fri_config.rate_bits,
             config.zero_knowledge && PlonkOracle::WIRES.blinding,
             config.fri_config.cap_height,
             timing,
             prover_data.fft_root_table.as_ref(),
+            rng.as_deref_mut().map(|rng| rng as _),
         )
     );
 
@@ -241,13 +341,14 @@ where
     let partial_products_zs_and_lookup_commitment = timed!(
         timing,
         "commit to partial products, Z's and, if any, lookup polynomials",
-        PolynomialBatch::from_values(
+        PolynomialBatch::from_values_helper(
             zs_partial_products_lookups,
             config.fri_config.rate_bits,
             config.zero_knowledge && PlonkOracle::ZS_PARTIAL_PRODUCTS.blinding,
             config.fri_config.cap_height,
             timing,
             prover_data.fft_root_table.as_ref(),
+            rng.as_deref_mut().map(|rng| rng as _),
         )
     );
 
@@ -289,13 +390,14 @@ where
     let quotient_polys_commitment = timed!(
         timing,
         "commit to quotient polys",
-        PolynomialBatch::<F, C, D>::from_coeffs(
+        PolynomialBatch::<F, C, D>::from_coeffs_helper(
             all_quotient_poly_chunks,
             config.fri_config.rate_bits,
             config.zero_knowledge && PlonkOracle::QUOTIENT.blinding,
             config.fri_config.cap_height,
             timing,
             prover_data.fft_root_table.as_ref(),
+            rng,
         )
     );
 
@@ -811,3 +913,98 @@ fn compute_quotient_polys<
         .map(|values| values.coset_ifft(F::coset_shift()))
         .collect()
 }
+
+#[cfg(all(test, feature = "timing"))]
+mod tests {
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::iop::generator::{GeneratedValues, SimpleGenerator};
+    use crate::iop::witness::PartitionWitness;
+    use crate::plonk::circuit_builder::CircuitBuilder;
+    use crate::plonk::circuit_data::{CircuitConfig, CommonCircuitData};
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+    use crate::util::serialization::{Buffer, IoError, IoResult};
+
+    const SLEEP: Duration = Duration::from_millis(50);
+
+    /// A generator which copies its input to its output after sleeping for `SLEEP`.
+    #[derive(Debug, Default)]
+    struct SlowGenerator {
+        input: Target,
+        output: Target,
+    }
+
+    impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D> for SlowGenerator {
+        fn id(&self) -> String {
+            "SlowGenerator".to_string()
+        }
+
+        fn dependencies(&self) -> Vec<Target> {
+            vec![self.input]
+        }
+
+        fn run_once(
+            &self,
+            witness: &PartitionWitness<F>,
+            out_buffer: &mut GeneratedValues<F>,
+        ) -> Result<()> {
+            sleep(SLEEP);
+            out_buffer.set_target(self.output, witness.get_target(self.input))
+        }
+
+        fn serialize(
+            &self,
+            _dst: &mut Vec<u8>,
+            _common_data: &CommonCircuitData<F, D>,
+        ) -> IoResult<()> {
+            Err(IoError)
+        }
+
+        fn deserialize(
+            _src: &mut Buffer,
+            _common_data: &CommonCircuitData<F, D>,
+        ) -> IoResult<Self> {
+            Err(IoError)
+        }
+    }
+
+    #[test]
+    fn test_profile_generators() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let input = builder.add_virtual_target();
+        let output = builder.add_virtual_target();
+        builder.add_simple_generator(SlowGenerator { input, output });
+        let x = builder.mul(output, output);
+        builder.register_public_input(x);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(input, F::from_canonical_u64(3))?;
+        let options = ProveOptions {
+            profile_generators: true,
+        };
+        let (proof, timings) = prove_with_options(
+            &data.prover_only,
+            &data.common,
+            pw,
+            &options,
+            &mut TimingTree::default(),
+        )?;
+        data.verify(proof)?;
+
+        let (slowest, &time) = timings.iter().max_by_key(|(_, &time)| time).unwrap();
+        assert_eq!(slowest, "SlowGenerator");
+        assert!(time >= SLEEP);
+
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 //! plonky2 verifier implementation.
 
+use core::fmt::{self, Display, Formatter};
+
 use anyhow::{ensure, Result};
 
 use crate::field::extension::Extendable;
 use crate::field::types::Field;
-use crate::fri::verifier::verify_fri_proof;
+use crate::fri::verifier::{verify_fri_proof, FriError};
 use crate::hash::hash_types::RichField;
 use crate::plonk::circuit_data::{CommonCircuitData, VerifierOnlyCircuitData};
 use crate::plonk::config::{GenericConfig, Hasher};
@@ -14,12 +16,50 @@ use crate::plonk::validate_shape::validate_proof_with_pis_shape;
 use crate::plonk::vanishing_poly::eval_vanishing_poly;
 use crate::plonk::vars::EvaluationVars;
 
+/// The stage of verification at which a proof was rejected.
+///
+/// Errors returned by the verifier carry one of these as context, so the failing stage can be
+/// recovered with `err.downcast_ref::<VerifyFailure>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyFailure {
+    /// The proof does not have the shape expected by the circuit.
+    Shape,
+    /// The vanishing polynomial identity for the given challenge does not hold at zeta, i.e. the
+    /// opened values are inconsistent with each other.
+    OpeningSet { challenge_index: usize },
+    /// An opening does not match the Merkle cap of the given oracle. Oracle indices follow
+    /// `PlonkOracle`: constants and sigmas, wires, Zs and partial products, then quotient.
+    MerkleCap { oracle_index: usize },
+    /// The FRI proof is invalid for some other reason.
+    Fri,
+}
+
+impl Display for VerifyFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shape => write!(f, "Proof has an invalid shape."),
+            Self::OpeningSet { challenge_index } => write!(
+                f,
+                "Vanishing polynomial identity {challenge_index} does not hold at zeta."
+            ),
+            Self::MerkleCap { oracle_index } => write!(
+                f,
+                "Opening does not match the Merkle cap of oracle {oracle_index}."
+            ),
+            Self::Fri => write!(f, "FRI verification failed."),
+        }
+    }
+}
+
+impl core::error::Error for VerifyFailure {}
+
 pub(crate) fn verify<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>(
     proof_with_pis: ProofWithPublicInputs<F, C, D>,
     verifier_data: &VerifierOnlyCircuitData<C, D>,
     common_data: &CommonCircuitData<F, D>,
 ) -> Result<()> {
-    validate_proof_with_pis_shape(&proof_with_pis, common_data)?;
+    validate_proof_with_pis_shape(&proof_with_pis, common_data)
+        .map_err(|e| e.context(VerifyFailure::Shape))?;
 
     let public_inputs_hash = proof_with_pis.get_public_inputs_hash();
     let challenges = proof_with_pis.get_challenges(
@@ -94,7 +134,10 @@ pub(crate) fn verify_with_challenges<
         .chunks(common_data.quotient_degree_factor)
         .enumerate()
     {
-        ensure!(vanishing_polys_zeta[i] == z_h_zeta * reduce_with_powers(chunk, zeta_pow_deg));
+        ensure!(
+            vanishing_polys_zeta[i] == z_h_zeta * reduce_with_powers(chunk, zeta_pow_deg),
+            VerifyFailure::OpeningSet { challenge_index: i }
+        );
     }
 
     let merkle_caps = &[
@@ -112,7 +155,136 @@ pub(crate) fn verify_with_challenges<
         merkle_caps,
         &proof.opening_proof,
         &common_data.fri_params,
-    )?;
+    )
+    .map_err(|e| {
+        let failure = match e.downcast_ref::<FriError>() {
+            Some(&FriError::InitialMerkleProof { oracle_index }) => {
+                VerifyFailure::MerkleCap { oracle_index }
+            }
+            _ => VerifyFailure::Fri,
+        };
+        e.context(failure)
+    })?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::field::types::Sample;
+    use crate::iop::witness::{PartialWitness, WitnessWrite};
+    use crate::plonk::circuit_builder::CircuitBuilder;
+    use crate::plonk::circuit_data::{CircuitConfig, CircuitData};
+    use crate::plonk::config::PoseidonGoldilocksConfig;
+
+    const D: usize = 2;
+    type C = PoseidonGoldilocksConfig;
+    type F = <C as GenericConfig<D>>::F;
+
+    fn prove_simple_circuit() -> Result<(ProofWithPublicInputs<F, C, D>, CircuitData<F, C, D>)> {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let x = builder.add_virtual_target();
+        let y = builder.add_virtual_target();
+        let z = builder.mul(x, y);
+        builder.register_public_input(z);
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(x, F::rand())?;
+        pw.set_target(y, F::rand())?;
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        Ok((proof, data))
+    }
+
+    /// Verifies `proof` against the challenges of the untampered `original`, so that a tampered
+    /// commitment is caught by the check that covers it rather than by shifted challenges.
+    fn verify_with_original_challenges(
+        original: &ProofWithPublicInputs<F, C, D>,
+        proof: Proof<F, C, D>,
+        data: &CircuitData<F, C, D>,
+    ) -> Result<()> {
+        let public_inputs_hash = original.get_public_inputs_hash();
+        let challenges = original.get_challenges(
+            public_inputs_hash,
+            &data.verifier_only.circuit_digest,
+            &data.common,
+        )?;
+        verify_with_challenges::<F, C, D>(
+            proof,
+            public_inputs_hash,
+            challenges,
+            &data.verifier_only,
+            &data.common,
+        )
+    }
+
+    fn failure(result: Result<()>) -> VerifyFailure {
+        result
+            .expect_err("verification should fail")
+            .downcast_ref::<VerifyFailure>()
+            .expect("error should carry a VerifyFailure")
+            .clone()
+    }
+
+    #[test]
+    fn test_verify_failure_shape() -> Result<()> {
+        let (mut proof, data) = prove_simple_circuit()?;
+        proof.proof.openings.wires.pop();
+        assert_eq!(
+            failure(verify(proof, &data.verifier_only, &data.common)),
+            VerifyFailure::Shape
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_failure_opening_set() -> Result<()> {
+        let (mut proof, data) = prove_simple_circuit()?;
+        proof.proof.openings.wires[0] += <F as Extendable<D>>::Extension::ONE;
+        assert_eq!(
+            failure(verify(proof, &data.verifier_only, &data.common)),
+            VerifyFailure::OpeningSet { challenge_index: 0 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_failure_merkle_cap() -> Result<()> {
+        let (original, data) = prove_simple_circuit()?;
+
+        let mut proof = original.proof.clone();
+        for hash in &mut proof.wires_cap.0 {
+            hash.elements[0] += F::ONE;
+        }
+        assert_eq!(
+            failure(verify_with_original_challenges(&original, proof, &data)),
+            VerifyFailure::MerkleCap { oracle_index: 1 }
+        );
+
+        let mut proof = original.proof.clone();
+        for hash in &mut proof.quotient_polys_cap.0 {
+            hash.elements[0] += F::ONE;
+        }
+        assert_eq!(
+            failure(verify_with_original_challenges(&original, proof, &data)),
+            VerifyFailure::MerkleCap { oracle_index: 3 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_failure_fri() -> Result<()> {
+        let (original, data) = prove_simple_circuit()?;
+        let mut proof = original.proof.clone();
+        proof.opening_proof.final_poly.coeffs[0] += <F as Extendable<D>>::Extension::ONE;
+        assert_eq!(
+            failure(verify_with_original_challenges(&original, proof, &data)),
+            VerifyFailure::Fri
+        );
+        Ok(())
+    }
+}
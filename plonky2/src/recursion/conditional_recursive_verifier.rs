@@ -128,7 +128,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
     }
 
     /// Computes `if b { h0 } else { h1 }`.
-    pub(crate) fn select_hash(
+    pub fn select_hash(
         &mut self,
         b: BoolTarget,
         h0: HashOutTarget,
@@ -140,7 +140,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilder<F, D> {
     }
 
     /// Computes `if b { cap0 } else { cap1 }`.
-    fn select_cap(
+    pub fn select_cap(
         &mut self,
         b: BoolTarget,
         cap0: &MerkleCapTarget,
@@ -353,6 +353,9 @@ mod tests {
     use super::*;
     use crate::field::types::Sample;
     use crate::gates::noop::NoopGate;
+    use crate::hash::hash_types::HashOut;
+    use crate::hash::merkle_tree::MerkleCap;
+    use crate::hash::poseidon::PoseidonHash;
     use crate::iop::witness::{PartialWitness, WitnessWrite};
     use crate::plonk::circuit_data::CircuitConfig;
     use crate::plonk::config::PoseidonGoldilocksConfig;
@@ -413,6 +416,51 @@ mod tests {
         data.verify(proof)
     }
 
+    #[test]
+    fn test_select_hash_and_cap() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let config = CircuitConfig::standard_recursion_config();
+        let cap_height = 2;
+
+        let h0 = HashOut::<F>::rand();
+        let h1 = HashOut::<F>::rand();
+        let cap0 =
+            MerkleCap::<F, PoseidonHash>((0..1 << cap_height).map(|_| HashOut::rand()).collect());
+        let cap1 =
+            MerkleCap::<F, PoseidonHash>((0..1 << cap_height).map(|_| HashOut::rand()).collect());
+
+        for b in [true, false] {
+            let mut builder = CircuitBuilder::<F, D>::new(config.clone());
+            let mut pw = PartialWitness::new();
+
+            let h0_t = builder.add_virtual_hash();
+            let h1_t = builder.add_virtual_hash();
+            let cap0_t = builder.add_virtual_cap(cap_height);
+            let cap1_t = builder.add_virtual_cap(cap_height);
+            pw.set_hash_target(h0_t, h0)?;
+            pw.set_hash_target(h1_t, h1)?;
+            pw.set_cap_target(&cap0_t, &cap0)?;
+            pw.set_cap_target(&cap1_t, &cap1)?;
+
+            let b_t = builder.constant_bool(b);
+            let selected_hash = builder.select_hash(b_t, h0_t, h1_t);
+            let selected_cap = builder.select_cap(b_t, &cap0_t, &cap1_t);
+
+            let expected_hash = builder.constant_hash(if b { h0 } else { h1 });
+            builder.connect_hashes(selected_hash, expected_hash);
+            let expected_cap = builder.constant_merkle_cap(if b { &cap0 } else { &cap1 });
+            builder.connect_merkle_caps(&selected_cap, &expected_cap);
+
+            let data = builder.build::<C>();
+            let proof = data.prove(pw)?;
+            data.verify(proof)?;
+        }
+
+        Ok(())
+    }
+
     fn init_logger() {
         let _ = env_logger::builder().format_timestamp(None).try_init();
     }
@@ -191,8 +191,14 @@ where
     C::Hasher: AlgebraicHasher<F>,
 {
     let pis = VerifierOnlyCircuitData::<C, D>::from_slice(&proof.public_inputs, common_data)?;
-    ensure!(verifier_data.constants_sigmas_cap == pis.constants_sigmas_cap);
-    ensure!(verifier_data.circuit_digest == pis.circuit_digest);
+    ensure!(
+        verifier_data.constants_sigmas_cap == pis.constants_sigmas_cap,
+        "Cyclic proof was generated with different constants and sigmas"
+    );
+    ensure!(
+        verifier_data.circuit_digest == pis.circuit_digest,
+        "Cyclic proof was generated with a different circuit digest"
+    );
 
     Ok(())
 }
@@ -355,6 +361,24 @@ mod tests {
             &cyclic_circuit_data.common,
         )?;
 
+        // Verifier data other than the circuit's own must be rejected.
+        let mut wrong_verifier_data = cyclic_circuit_data.verifier_only.clone();
+        wrong_verifier_data.circuit_digest.elements[0] += F::ONE;
+        assert!(check_cyclic_proof_verifier_data(
+            &proof,
+            &wrong_verifier_data,
+            &cyclic_circuit_data.common,
+        )
+        .is_err());
+        let mut wrong_verifier_data = cyclic_circuit_data.verifier_only.clone();
+        wrong_verifier_data.constants_sigmas_cap.0[0].elements[0] += F::ONE;
+        assert!(check_cyclic_proof_verifier_data(
+            &proof,
+            &wrong_verifier_data,
+            &cyclic_circuit_data.common,
+        )
+        .is_err());
+
         // Verify that the proof correctly computes a repeated hash.
         let initial_hash = &proof.public_inputs[..4];
         let hash = &proof.public_inputs[4..8];
@@ -235,6 +235,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_set_proof_target_query_round_mismatch() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let config = CircuitConfig::standard_recursion_config();
+
+        let (proof, _vd, common_data) = dummy_proof::<F, C, D>(&config, 1_000)?;
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let pt = builder.add_virtual_proof_with_pis(&common_data);
+
+        let mut pw = PartialWitness::new();
+        pw.set_proof_with_pis_target(&pt, &proof)?;
+
+        // A proof with more or fewer query rounds than the target is rejected.
+        let mut extra_round = proof.clone();
+        let round = extra_round.proof.opening_proof.query_round_proofs[0].clone();
+        extra_round
+            .proof
+            .opening_proof
+            .query_round_proofs
+            .push(round);
+        assert!(PartialWitness::new()
+            .set_proof_with_pis_target(&pt, &extra_round)
+            .is_err());
+        let mut missing_round = proof;
+        missing_round.proof.opening_proof.query_round_proofs.pop();
+        assert!(PartialWitness::new()
+            .set_proof_with_pis_target(&pt, &missing_round)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_recursive_verifier_one_lookup() -> Result<()> {
         init_logger();
@@ -1,5 +1,6 @@
 #[cfg(not(feature = "std"))]
 use alloc::{
+    format,
     string::{String, ToString},
     vec,
     vec::Vec,
@@ -110,6 +111,33 @@ impl ContextTree {
         }
     }
 
+    /// The gate count contributed by each context below the root, in the order they were opened.
+    /// Each context is named by the `" > "`-separated path of scopes leading to it.
+    pub fn gate_counts(&self, current_gate_count: usize) -> Vec<(String, usize)> {
+        let mut counts = Vec::new();
+        for child in &self.children {
+            child.gate_counts_helper(current_gate_count, "", &mut counts);
+        }
+        counts
+    }
+
+    fn gate_counts_helper(
+        &self,
+        current_gate_count: usize,
+        prefix: &str,
+        counts: &mut Vec<(String, usize)>,
+    ) {
+        let name = if prefix.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{prefix} > {}", self.name)
+        };
+        counts.push((name.clone(), self.gate_count_delta(current_gate_count)));
+        for child in &self.children {
+            child.gate_counts_helper(current_gate_count, &name, counts);
+        }
+    }
+
     pub fn print(&self, current_gate_count: usize) {
         self.print_helper(current_gate_count, 0);
     }
@@ -200,6 +200,17 @@ pub trait Read {
         (0..length).map(|_| self.read_field_ext::<F, D>()).collect()
     }
 
+    /// Reads a length-prefixed vector of elements from the field extension of `F` from `self`,
+    /// as written by [`Write::write_field_ext_vec_prefixed`].
+    #[inline]
+    fn read_field_ext_vec_prefixed<F, const D: usize>(&mut self) -> IoResult<Vec<F::Extension>>
+    where
+        F: RichField + Extendable<D>,
+    {
+        let length = self.read_usize()?;
+        self.read_field_ext_vec::<F, D>(length)
+    }
+
     /// Reads a Target from `self.`
     #[inline]
     fn read_target(&mut self) -> IoResult<Target> {
@@ -1294,6 +1305,20 @@ pub trait Write {
         Ok(())
     }
 
+    /// Writes a vector `v` of elements from the field extension of `F` to `self`, preceded by its
+    /// length, so that it can be read back without knowing the length in advance.
+    #[inline]
+    fn write_field_ext_vec_prefixed<F, const D: usize>(
+        &mut self,
+        v: &[F::Extension],
+    ) -> IoResult<()>
+    where
+        F: RichField + Extendable<D>,
+    {
+        self.write_usize(v.len())?;
+        self.write_field_ext_vec::<F, D>(v)
+    }
+
     /// Writes a Target `x` to `self.`
     #[inline]
     fn write_target(&mut self, x: Target) -> IoResult<()> {
@@ -2231,3 +2256,31 @@ impl Read for Buffer<'_> {
         generator_serializer.read_generator(self, common_data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::goldilocks_field::GoldilocksField;
+    use crate::field::types::{Field, Sample};
+
+    #[test]
+    fn test_field_ext_vec_prefixed_round_trip() -> IoResult<()> {
+        const D: usize = 2;
+        type F = GoldilocksField;
+        type FE = <F as Extendable<D>>::Extension;
+
+        for len in [0, 1, 10] {
+            let v = FE::rand_vec(len);
+            let mut bytes = Vec::new();
+            bytes.write_field_ext_vec_prefixed::<F, D>(&v)?;
+            bytes.write_field::<F>(F::ONE)?;
+
+            let mut buffer = Buffer::new(&bytes);
+            assert_eq!(buffer.read_field_ext_vec_prefixed::<F, D>()?, v);
+            assert_eq!(buffer.read_field::<F>()?, F::ONE);
+            assert!(buffer.is_empty());
+        }
+
+        Ok(())
+    }
+}
@@ -121,6 +121,18 @@ impl TimingTree {
     #[cfg(not(feature = "timing"))]
     pub fn pop(&mut self) {}
 
+    /// The name of this scope.
+    #[cfg(feature = "timing")]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The scopes opened directly within this one, in order.
+    #[cfg(feature = "timing")]
+    pub fn children(&self) -> &[TimingTree] {
+        &self.children
+    }
+
     #[cfg(feature = "timing")]
     fn duration(&self) -> Duration {
         self.exit_time
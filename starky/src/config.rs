@@ -5,7 +5,7 @@
 //! proofs, with a targeted security level of 100 bits.
 
 #[cfg(not(feature = "std"))]
-use alloc::format;
+use alloc::{format, vec::Vec};
 
 use anyhow::{anyhow, Result};
 use plonky2::field::extension::Extendable;
@@ -13,9 +13,10 @@ use plonky2::field::types::Field;
 use plonky2::fri::reduction_strategies::FriReductionStrategy;
 use plonky2::fri::{FriConfig, FriParams};
 use plonky2::hash::hash_types::RichField;
+use plonky2::util::serialization::{Buffer, IoResult, Read, Write};
 
 /// A configuration containing the different parameters used by the STARK prover.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StarkConfig {
     /// The targeted security level for the proofs generated with this configuration.
     pub security_bits: usize,
@@ -60,11 +61,48 @@ impl StarkConfig {
         }
     }
 
+    /// A configuration aimed at minimizing the cost of verifying proofs on Ethereum L1.
+    /// Targets ~100 bit conjectured security.
+    ///
+    /// On-chain verification cost is dominated by the calldata and hashing of each FRI query, so
+    /// this trades prover time for fewer queries: a rate of 16 and 20 bits of grinding bring the
+    /// number of query rounds down to 20, against 84 for [`StarkConfig::standard_fast_config`].
+    /// Folding with arity 32 further shortens the Merkle paths opened by each query. In exchange,
+    /// the prover commits to LDEs 8 times larger.
+    pub const fn l1_optimized() -> Self {
+        Self {
+            security_bits: 100,
+            num_challenges: 2,
+            fri_config: FriConfig {
+                rate_bits: 4,
+                cap_height: 4,
+                proof_of_work_bits: 20,
+                reduction_strategy: FriReductionStrategy::ConstantArityBits(5, 5),
+                num_query_rounds: 20,
+            },
+        }
+    }
+
     /// Outputs the [`FriParams`] used during the FRI sub-protocol by this [`StarkConfig`].
     pub fn fri_params(&self, degree_bits: usize) -> FriParams {
         self.fri_config.fri_params(degree_bits, false)
     }
 
+    /// Serializes this [`StarkConfig`], including its embedded [`FriConfig`].
+    pub fn to_buffer(&self, buffer: &mut Vec<u8>) -> IoResult<()> {
+        buffer.write_usize(self.security_bits)?;
+        buffer.write_usize(self.num_challenges)?;
+        buffer.write_fri_config(&self.fri_config)
+    }
+
+    /// Deserializes a [`StarkConfig`] written with [`StarkConfig::to_buffer`].
+    pub fn from_buffer(buffer: &mut Buffer) -> IoResult<Self> {
+        let security_bits = buffer.read_usize()?;
+        let num_challenges = buffer.read_usize()?;
+        let fri_config = buffer.read_fri_config()?;
+        Ok(Self::new(security_bits, num_challenges, fri_config))
+    }
+
     /// Checks that this STARK configuration is consistent, i.e. that the different
     /// parameters meet the targeted security level.
     pub fn check_config<F: RichField + Extendable<D>, const D: usize>(&self) -> Result<()> {
@@ -99,6 +137,7 @@ impl StarkConfig {
 #[cfg(test)]
 mod tests {
     use plonky2::field::goldilocks_field::GoldilocksField;
+    use plonky2::util::serialization::Remaining;
 
     use super::*;
 
@@ -110,6 +149,9 @@ mod tests {
         let config = StarkConfig::standard_fast_config();
         assert!(config.check_config::<F, D>().is_ok());
 
+        let l1_config = StarkConfig::l1_optimized();
+        assert!(l1_config.check_config::<F, D>().is_ok());
+
         let high_rate_config = StarkConfig::new(
             100,
             2,
@@ -144,4 +186,28 @@ mod tests {
         // bits of security for FRI, which falls short of the 100 bits of security target.
         assert!(too_few_queries_config.check_config::<F, D>().is_err());
     }
+
+    #[test]
+    fn test_config_serialization() -> IoResult<()> {
+        let custom_config = StarkConfig::new(
+            80,
+            3,
+            FriConfig {
+                rate_bits: 3,
+                cap_height: 2,
+                proof_of_work_bits: 10,
+                reduction_strategy: FriReductionStrategy::MinSize(Some(8)),
+                num_query_rounds: 28,
+            },
+        );
+
+        for config in [StarkConfig::standard_fast_config(), custom_config] {
+            let mut bytes = Vec::new();
+            config.to_buffer(&mut bytes)?;
+            let mut buffer = Buffer::new(&bytes);
+            assert_eq!(StarkConfig::from_buffer(&mut buffer)?, config);
+            assert!(buffer.is_empty());
+        }
+        Ok(())
+    }
 }
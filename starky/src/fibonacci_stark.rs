@@ -15,7 +15,7 @@ use plonky2::plonk::circuit_builder::CircuitBuilder;
 
 use crate::constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer};
 use crate::evaluation_frame::{StarkEvaluationFrame, StarkFrame};
-use crate::stark::Stark;
+use crate::stark::{Stark, TraceGenerator};
 use crate::util::trace_rows_to_poly_values;
 
 /// Toy STARK system used for testing.
@@ -42,19 +42,6 @@ impl<F: RichField + Extendable<D>, const D: usize> FibonacciStark<F, D> {
             _phantom: PhantomData,
         }
     }
-
-    /// Generate the trace using `x0, x1` as initial state values.
-    fn generate_trace(&self, x0: F, x1: F) -> Vec<PolynomialValues<F>> {
-        let trace_rows = (0..self.num_rows)
-            .scan([x0, x1], |acc, _| {
-                let tmp = *acc;
-                acc[0] = tmp[1];
-                acc[1] = tmp[0] + tmp[1];
-                Some(tmp)
-            })
-            .collect::<Vec<_>>();
-        trace_rows_to_poly_values(trace_rows)
-    }
 }
 
 const FIBONACCI_COLUMNS: usize = 2;
@@ -132,6 +119,24 @@ impl<F: RichField + Extendable<D>, const D: usize> Stark<F, D> for FibonacciStar
     }
 }
 
+impl<F: RichField + Extendable<D>, const D: usize> TraceGenerator<F, D> for FibonacciStark<F, D> {
+    /// The initial state `[x0, x1]`.
+    type TraceInputs = [F; 2];
+
+    /// Generate the trace using `x0, x1` as initial state values.
+    fn generate_trace(&self, inputs: &Self::TraceInputs) -> Vec<PolynomialValues<F>> {
+        let trace_rows = (0..self.num_rows)
+            .scan(*inputs, |acc, _| {
+                let tmp = *acc;
+                acc[0] = tmp[1];
+                acc[1] = tmp[0] + tmp[1];
+                Some(tmp)
+            })
+            .collect::<Vec<_>>();
+        trace_rows_to_poly_values(trace_rows)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(not(feature = "std"))]
@@ -142,6 +147,7 @@ mod tests {
     use plonky2::field::extension::Extendable;
     use plonky2::field::types::Field;
     use plonky2::hash::hash_types::RichField;
+    use plonky2::iop::challenger::Challenger;
     use plonky2::iop::witness::PartialWitness;
     use plonky2::plonk::circuit_builder::CircuitBuilder;
     use plonky2::plonk::circuit_data::CircuitConfig;
@@ -150,13 +156,13 @@ mod tests {
 
     use crate::config::StarkConfig;
     use crate::fibonacci_stark::FibonacciStark;
-    use crate::proof::StarkProofWithPublicInputs;
-    use crate::prover::prove;
+    use crate::proof::{CompressedStarkProofWithPublicInputs, StarkProofWithPublicInputs};
+    use crate::prover::{prove, prove_from_inputs};
     use crate::recursive_verifier::{
         add_virtual_stark_proof_with_pis, set_stark_proof_with_pis_target,
         verify_stark_proof_circuit,
     };
-    use crate::stark::Stark;
+    use crate::stark::{Stark, TraceGenerator};
     use crate::stark_testing::{test_stark_circuit_constraints, test_stark_low_degree};
     use crate::verifier::verify_stark_proof;
 
@@ -169,24 +175,239 @@ mod tests {
         (0..n).fold((x0, x1), |x, _| (x.1, x.0 + x.1)).1
     }
 
+    /// Proves a 32-row Fibonacci STARK starting from `0, 1`.
+    fn fibonacci_proof(config: &StarkConfig) -> Result<(S, StarkProofWithPublicInputs<F, C, D>)> {
+        let num_rows = 1 << 5;
+        let public_inputs = [F::ZERO, F::ONE, fibonacci(num_rows - 1, F::ZERO, F::ONE)];
+
+        let stark = S::new(num_rows);
+        let trace = stark.generate_trace(&[public_inputs[0], public_inputs[1]]);
+        let proof = prove::<F, C, S, D>(
+            stark,
+            config,
+            trace,
+            &public_inputs,
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+        Ok((stark, proof))
+    }
+
     #[test]
     fn test_fibonacci_stark() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
+    }
+
+    #[test]
+    #[cfg(feature = "timing")]
+    fn test_fibonacci_stark_verifier_timing() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+
+        let mut timing = TimingTree::new("verify", log::Level::Debug);
+        verify_stark_proof(stark, proof, &config, None, &mut timing)?;
+        let span_names = timing
+            .children()
+            .iter()
+            .map(|child| child.name())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            span_names,
+            [
+                "derive challenges",
+                "check opening consistency",
+                "verify FRI proof"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_fibonacci_stark_json() -> Result<()> {
+        use plonky2::field::types::Field64;
+
+        use crate::proof::StarkProof;
+
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+
+        let json = proof.to_json()?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["public_inputs"][1], "1");
+        assert!(value["proof"]["opening_proof"]["pow_witness"].is_string());
+
+        let decoded = StarkProofWithPublicInputs::<F, C, D>::from_json(&json)?;
+        assert_eq!(decoded, proof);
+        assert_eq!(
+            StarkProof::<F, C, D>::from_json(&proof.proof.to_json()?)?,
+            proof.proof
+        );
+
+        // Field elements must be canonical.
+        let non_canonical = json.replacen("\"1\"", &format!("\"{}\"", F::ORDER + 1), 1);
+        assert!(StarkProofWithPublicInputs::<F, C, D>::from_json(&non_canonical).is_err());
+
+        verify_stark_proof(stark, decoded, &config, None, &mut TimingTree::default())
+    }
+
+    #[test]
+    fn test_fibonacci_stark_from_inputs() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+
+        // The trace generated through the trait must be the one committed to by `prove`.
+        let (_, expected_proof) = fibonacci_proof(&config)?;
+        assert_eq!(proof.proof.trace_cap, expected_proof.proof.trace_cap);
+
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
+    }
+
+    #[test]
+    fn test_fibonacci_stark_compressed() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+        let degree_bits = proof.proof.recover_degree_bits(&config);
+
+        let compressed_proof = proof.clone().compress(&config);
+        let proof_bytes = serde_cbor::to_vec(&proof)?;
+        let compressed_proof_bytes = serde_cbor::to_vec(&compressed_proof)?;
+        assert!(compressed_proof_bytes.len() < proof_bytes.len());
+
+        let compressed_proof: CompressedStarkProofWithPublicInputs<F, C, D> =
+            serde_cbor::from_slice(&compressed_proof_bytes)?;
+        let decompressed_proof = compressed_proof.decompress(&stark, &config, degree_bits)?;
+        assert_eq!(serde_cbor::to_vec(&decompressed_proof)?, proof_bytes);
+
+        verify_stark_proof(
+            stark,
+            decompressed_proof,
+            &config,
+            None,
+            &mut TimingTree::default(),
+        )
+    }
+
+    #[test]
+    fn test_fibonacci_stark_proof_equality_and_hash() -> Result<()> {
+        type H = <C as GenericConfig<D>>::Hasher;
+
         let config = StarkConfig::standard_fast_config();
         let num_rows = 1 << 5;
-        let public_inputs = [F::ZERO, F::ONE, fibonacci(num_rows - 1, F::ZERO, F::ONE)];
+        let prove = |inputs: [F; 2]| {
+            let public_inputs = [
+                inputs[0],
+                inputs[1],
+                fibonacci(num_rows - 1, inputs[0], inputs[1]),
+            ];
+            prove_from_inputs::<F, C, S, D>(
+                S::new(num_rows),
+                &config,
+                &inputs,
+                &public_inputs,
+                None,
+                &mut TimingTree::default(),
+                None,
+            )
+        };
+
+        // Proving is deterministic, so the same statement yields the same proof.
+        let proof = prove([F::ZERO, F::ONE])?.proof;
+        let same_proof = prove([F::ZERO, F::ONE])?.proof;
+        assert_eq!(proof, same_proof);
+        assert_eq!(
+            proof.stark_proof_hash::<H>(),
+            same_proof.stark_proof_hash::<H>()
+        );
+
+        let other_proof = prove([F::ONE, F::ONE])?.proof;
+        assert_ne!(proof, other_proof);
+        assert_ne!(
+            proof.stark_proof_hash::<H>(),
+            other_proof.stark_proof_hash::<H>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fibonacci_stark_size_breakdown() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let proof = fibonacci_proof(&config)?.1.proof;
+
+        let sizes = proof.size_breakdown();
+        assert_eq!(sizes.total(), proof.to_bytes().len());
+        assert!(sizes.trace_cap > 0);
+        assert!(sizes.quotient_polys_cap > 0);
+        assert!(sizes.openings > 0);
+        assert!(sizes.fri_query_rounds > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fibonacci_stark_l1_optimized() -> Result<()> {
+        let l1_config = StarkConfig::l1_optimized();
+        l1_config.check_config::<F, D>()?;
+        let (stark, l1_proof) = fibonacci_proof(&l1_config)?;
+        let (_, fast_proof) = fibonacci_proof(&StarkConfig::standard_fast_config())?;
+        assert!(l1_proof.proof.to_bytes().len() < fast_proof.proof.to_bytes().len());
+
+        verify_stark_proof(
+            stark,
+            l1_proof,
+            &l1_config,
+            None,
+            &mut TimingTree::default(),
+        )
+    }
+
+    /// Public inputs are observed at the start of the Fiat-Shamir transcript, so a proof cannot be
+    /// checked against other public inputs, whether or not the constraints reference them.
+    #[test]
+    fn test_fibonacci_stark_public_inputs_binding() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let num_rows = 1 << 5;
+        let inputs = [F::ZERO, F::ONE];
+        let public_inputs = [
+            inputs[0],
+            inputs[1],
+            fibonacci(num_rows - 1, inputs[0], inputs[1]),
+        ];
 
         let stark = S::new(num_rows);
-        let trace = stark.generate_trace(public_inputs[0], public_inputs[1]);
-        let proof = prove::<F, C, S, D>(
+        let proof = prove_from_inputs::<F, C, S, D>(
             stark,
             &config,
-            trace,
+            &inputs,
             &public_inputs,
             None,
             &mut TimingTree::default(),
+            None,
         )?;
 
-        verify_stark_proof(stark, proof, &config, None)
+        let mut swapped_proof = proof.clone();
+        swapped_proof.public_inputs.swap(0, 1);
+
+        let challenges = proof.get_challenges(&mut Challenger::new(), None, false, &config, None);
+        let swapped_challenges =
+            swapped_proof.get_challenges(&mut Challenger::new(), None, false, &config, None);
+        assert_ne!(challenges.stark_zeta, swapped_challenges.stark_zeta);
+        assert_ne!(challenges.stark_alphas, swapped_challenges.stark_alphas);
+
+        assert!(verify_stark_proof(
+            stark,
+            swapped_proof,
+            &config,
+            None,
+            &mut TimingTree::default()
+        )
+        .is_err());
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
     }
 
     #[test]
@@ -208,27 +429,41 @@ mod tests {
         init_logger();
 
         let config = StarkConfig::standard_fast_config();
-        let degree_bits = 5;
-        let num_rows = 1 << degree_bits;
-        let public_inputs = [F::ZERO, F::ONE, fibonacci(num_rows - 1, F::ZERO, F::ONE)];
 
         // Test first STARK
-        let stark = S::new(num_rows);
-        let trace = stark.generate_trace(public_inputs[0], public_inputs[1]);
-        let proof = prove::<F, C, S, D>(
+        let (stark, proof) = fibonacci_proof(&config)?;
+        verify_stark_proof(
             stark,
+            proof.clone(),
             &config,
-            trace,
-            &public_inputs,
             None,
             &mut TimingTree::default(),
         )?;
-        verify_stark_proof(stark, proof.clone(), &config, None)?;
-        assert_eq!(degree_bits, proof.proof.recover_degree_bits(&config));
+        assert_eq!(proof.proof.recover_degree_bits(&config), 5);
 
         recursive_proof::<F, C, S, C, D>(stark, proof, &config, true)
     }
 
+    #[test]
+    fn test_recursive_stark_verifier_extra_query_rounds() -> Result<()> {
+        init_logger();
+
+        let circuit_config = StarkConfig::standard_fast_config();
+        let mut config = circuit_config.clone();
+        config.fri_config.num_query_rounds += 16;
+        let (stark, proof) = fibonacci_proof(&config)?;
+        verify_stark_proof(
+            stark,
+            proof.clone(),
+            &config,
+            None,
+            &mut TimingTree::default(),
+        )?;
+
+        // The circuit is built for fewer query rounds than the proof contains.
+        recursive_proof::<F, C, S, C, D>(stark, proof, &circuit_config, false)
+    }
+
     fn recursive_proof<
         F: RichField + Extendable<D>,
         C: GenericConfig<D, F = F>,
@@ -267,6 +502,33 @@ mod tests {
         let _ = env_logger::builder().format_timestamp(None).try_init();
     }
 
+    #[test]
+    fn test_recursive_stark_verifier_public_inputs() -> Result<()> {
+        let config = StarkConfig::standard_fast_config();
+        let (stark, proof) = fibonacci_proof(&config)?;
+        let degree_bits = proof.proof.recover_degree_bits(&config);
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let mut pw = PartialWitness::new();
+        let pt = add_virtual_stark_proof_with_pis(&mut builder, &stark, &config, degree_bits, 0, 0);
+        set_stark_proof_with_pis_target(&mut pw, &pt, &proof, degree_bits, builder.zero())?;
+
+        let pi_targets = verify_stark_proof_circuit::<F, C, S, D>(
+            &mut builder,
+            stark,
+            pt.clone(),
+            &config,
+            None,
+        );
+        assert_eq!(pi_targets, pt.public_inputs);
+        builder.register_public_inputs(&pi_targets);
+
+        let data = builder.build::<C>();
+        let outer_proof = data.prove(pw)?;
+        assert_eq!(outer_proof.public_inputs, proof.public_inputs);
+        data.verify(outer_proof)
+    }
+
     #[test]
     fn test_recursive_verifier_with_multiple_degree_bits() -> Result<()> {
         init_logger();
@@ -290,7 +552,7 @@ mod tests {
                 let num_rows = 1 << degree_bits;
                 let public_inputs = [F::ZERO, F::ONE, fibonacci(num_rows - 1, F::ZERO, F::ONE)];
                 let stark = S::new(num_rows);
-                let trace = stark.generate_trace(public_inputs[0], public_inputs[1]);
+                let trace = stark.generate_trace(&[public_inputs[0], public_inputs[1]]);
 
                 // Generate proof with the specified verifier degree
                 prove::<F, C, S, D>(
@@ -300,6 +562,7 @@ mod tests {
                     &public_inputs,
                     Some(verifier_fri_params.clone()),
                     &mut TimingTree::default(),
+                    None,
                 )
                 .unwrap()
             })
@@ -309,7 +572,13 @@ mod tests {
         let num_rows = 1 << verifier_degree_bits;
         let stark = S::new(num_rows);
         for p in proofs.clone() {
-            verify_stark_proof(stark, p, &stark_config, Some(verifier_fri_params.clone()))?;
+            verify_stark_proof(
+                stark,
+                p,
+                &stark_config,
+                Some(verifier_fri_params.clone()),
+                &mut TimingTree::default(),
+            )?;
         }
 
         let recursive_verification_circuit_config = CircuitConfig::standard_recursion_config();
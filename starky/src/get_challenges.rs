@@ -1,6 +1,6 @@
 use plonky2::field::extension::Extendable;
 use plonky2::field::polynomial::PolynomialCoeffs;
-use plonky2::fri::proof::{FriProof, FriProofTarget};
+use plonky2::fri::proof::{CompressedFriProof, FriProof, FriProofTarget};
 use plonky2::fri::prover::final_poly_coeff_len;
 use plonky2::fri::FriParams;
 use plonky2::gadgets::polynomial::PolynomialCoeffsExtTarget;
@@ -189,6 +189,53 @@ where
     }
 }
 
+impl<F, C, const D: usize> CompressedStarkProofWithPublicInputs<F, C, D>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+{
+    /// Computes all Fiat-Shamir challenges used in the compressed STARK proof.
+    /// Since the trace length cannot be recovered from a compressed proof, it
+    /// has to be provided through `degree_bits`.
+    pub(crate) fn get_challenges(
+        &self,
+        challenger: &mut Challenger<F, C::Hasher>,
+        config: &StarkConfig,
+        degree_bits: usize,
+    ) -> StarkProofChallenges<F, D> {
+        challenger.observe_elements(&self.public_inputs);
+
+        let CompressedStarkProof {
+            trace_cap,
+            auxiliary_polys_cap,
+            quotient_polys_cap,
+            openings,
+            opening_proof:
+                CompressedFriProof {
+                    commit_phase_merkle_caps,
+                    final_poly,
+                    pow_witness,
+                    ..
+                },
+        } = &self.proof;
+
+        get_challenges::<F, C, D>(
+            challenger,
+            None,
+            Some(trace_cap),
+            auxiliary_polys_cap.as_ref(),
+            quotient_polys_cap.as_ref(),
+            openings,
+            commit_phase_merkle_caps,
+            final_poly,
+            *pow_witness,
+            config,
+            degree_bits,
+            None,
+        )
+    }
+}
+
 /// Circuit version of `get_challenges`, with the same flexibility around
 /// `trace_cap` being passed as an `Option`.
 fn get_challenges_target<F, C, const D: usize>(
@@ -0,0 +1,463 @@
+//! JSON encoding of proofs, in which field elements are rendered as canonical decimal strings so
+//! that they survive parsers which read every JSON number as an `f64`.
+//!
+//! Only values of the base field `F` get the string encoding; they are recognized by the newtype
+//! struct name `F` serializes itself with. Every other integer is left as a JSON number.
+
+use core::marker::PhantomData;
+
+use anyhow::Result;
+use plonky2::hash::hash_types::RichField;
+use serde::de::value::U64Deserializer;
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// Serializes `value` to JSON, encoding every element of `F` as a canonical decimal string.
+pub(crate) fn to_json<F: RichField, T: Serialize>(value: &T) -> Result<String> {
+    let json = value.serialize(FieldSerializer::<F>::new())?;
+    Ok(serde_json::to_string(&json)?)
+}
+
+/// Inverse of [`to_json`]. Elements of `F` must be canonical decimal strings.
+pub(crate) fn from_json<F: RichField, T: for<'de> Deserialize<'de>>(json: &str) -> Result<T> {
+    let json: Value = serde_json::from_str(json)?;
+    Ok(T::deserialize(FieldDeserializer::<F>::new(json))?)
+}
+
+/// The name `F` passes to `serialize_newtype_struct` and `deserialize_newtype_struct`.
+fn field_name<F: RichField>() -> &'static str {
+    let name = core::any::type_name::<F>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn unsupported<E: ser::Error>(kind: &str) -> E {
+    E::custom(format_args!("JSON proof encoding does not support {kind}"))
+}
+
+/// Serializes a value into a [`Value`], like `serde_json::value::Serializer`, except that elements
+/// of `F` become decimal strings.
+struct FieldSerializer<F> {
+    _phantom: PhantomData<F>,
+}
+
+impl<F> Clone for FieldSerializer<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for FieldSerializer<F> {}
+
+impl<F: RichField> FieldSerializer<F> {
+    const fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! forward_to_value_serializer {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Value, serde_json::Error> {
+                serde_json::value::Serializer.$method(v)
+            }
+        )*
+    };
+}
+
+impl<F: RichField> ser::Serializer for FieldSerializer<F> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SerializeVec<F>;
+    type SerializeTuple = SerializeVec<F>;
+    type SerializeTupleStruct = SerializeVec<F>;
+    type SerializeTupleVariant = ser::Impossible<Value, serde_json::Error>;
+    type SerializeMap = ser::Impossible<Value, serde_json::Error>;
+    type SerializeStruct = SerializeStruct<F>;
+    type SerializeStructVariant = ser::Impossible<Value, serde_json::Error>;
+
+    forward_to_value_serializer!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_none(self) -> Result<Value, serde_json::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, serde_json::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, serde_json::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Value, serde_json::Error> {
+        Err(unsupported("enums"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Value, serde_json::Error> {
+        if name != field_name::<F>() {
+            return value.serialize(self);
+        }
+        // `F` may hold a non-canonical representative of its value, so canonicalize it.
+        let x = value
+            .serialize(serde_json::value::Serializer)?
+            .as_u64()
+            .ok_or_else(|| ser::Error::custom(format_args!("{name} is not a u64 newtype")))?;
+        Ok(Value::String(
+            F::from_noncanonical_u64(x).to_canonical_u64().to_string(),
+        ))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Value, serde_json::Error> {
+        Err(unsupported("enums"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeVec<F>, serde_json::Error> {
+        Ok(SerializeVec {
+            ser: self,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeVec<F>, serde_json::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeVec<F>, serde_json::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, serde_json::Error> {
+        Err(unsupported("enums"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, serde_json::Error> {
+        Err(unsupported("maps"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SerializeStruct<F>, serde_json::Error> {
+        Ok(SerializeStruct {
+            ser: self,
+            fields: Map::new(),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, serde_json::Error> {
+        Err(unsupported("enums"))
+    }
+}
+
+struct SerializeVec<F> {
+    ser: FieldSerializer<F>,
+    values: Vec<Value>,
+}
+
+impl<F: RichField> ser::SerializeSeq for SerializeVec<F> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.values.push(value.serialize(self.ser)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, serde_json::Error> {
+        Ok(Value::Array(self.values))
+    }
+}
+
+impl<F: RichField> ser::SerializeTuple for SerializeVec<F> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, serde_json::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<F: RichField> ser::SerializeTupleStruct for SerializeVec<F> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, serde_json::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct SerializeStruct<F> {
+    ser: FieldSerializer<F>,
+    fields: Map<String, Value>,
+}
+
+impl<F: RichField> ser::SerializeStruct for SerializeStruct<F> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.fields
+            .insert(key.to_string(), value.serialize(self.ser)?);
+        Ok(())
+    }
+
+    fn skip_field(&mut self, _key: &'static str) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, serde_json::Error> {
+        Ok(Value::Object(self.fields))
+    }
+}
+
+/// Deserializes from a [`Value`], like `serde_json::Value` itself, except that elements of `F`
+/// are read from canonical decimal strings.
+struct FieldDeserializer<F> {
+    value: Value,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField> FieldDeserializer<F> {
+    const fn new(value: Value) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, F: RichField> Deserializer<'de> for FieldDeserializer<F> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self.value {
+            Value::Array(values) => visitor.visit_seq(SeqDeserializer::<F, _> {
+                iter: values.into_iter(),
+                _phantom: PhantomData,
+            }),
+            Value::Object(fields) => visitor.visit_map(MapDeserializer::<F, _> {
+                iter: fields.into_iter(),
+                value: None,
+                _phantom: PhantomData,
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        if name != field_name::<F>() {
+            return visitor.visit_newtype_struct(self);
+        }
+        let x = match &self.value {
+            Value::String(s) => s.parse::<u64>().map_err(de::Error::custom)?,
+            value => {
+                return Err(de::Error::custom(format_args!(
+                    "Expected a field element string, found {value}"
+                )))
+            }
+        };
+        if x >= F::ORDER {
+            return Err(de::Error::custom(format_args!(
+                "Non-canonical field element {x}"
+            )));
+        }
+        let x: U64Deserializer<serde_json::Error> = x.into_deserializer();
+        visitor.visit_newtype_struct(x)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<F, I> {
+    iter: I,
+    _phantom: PhantomData<F>,
+}
+
+impl<'de, F: RichField, I: Iterator<Item = Value>> SeqAccess<'de> for SeqDeserializer<F, I> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, serde_json::Error> {
+        self.iter
+            .next()
+            .map(|value| seed.deserialize(FieldDeserializer::<F>::new(value)))
+            .transpose()
+    }
+}
+
+struct MapDeserializer<F, I> {
+    iter: I,
+    value: Option<Value>,
+    _phantom: PhantomData<F>,
+}
+
+impl<'de, F: RichField, I: Iterator<Item = (String, Value)>> MapAccess<'de>
+    for MapDeserializer<F, I>
+{
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, serde_json::Error> {
+        self.iter
+            .next()
+            .map(|(key, value)| {
+                self.value = Some(value);
+                seed.deserialize(Value::String(key))
+            })
+            .transpose()
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<T::Value, serde_json::Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("Value requested before its key"))?;
+        seed.deserialize(FieldDeserializer::<F>::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+    use plonky2::field::types::{Field, Field64};
+    use serde::Serialize;
+
+    use super::*;
+
+    type F = GoldilocksField;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Mixed {
+        count: u64,
+        element: F,
+        elements: Option<Vec<F>>,
+    }
+
+    #[test]
+    fn test_only_field_elements_are_strings() -> Result<()> {
+        let mixed = Mixed {
+            count: u64::MAX,
+            // A non-canonical representative of 1.
+            element: GoldilocksField(F::ORDER + 1),
+            elements: Some(vec![F::ZERO, F::NEG_ONE]),
+        };
+
+        let json = to_json::<F, _>(&mixed)?;
+        let value: Value = serde_json::from_str(&json)?;
+        assert_eq!(value["count"], u64::MAX);
+        assert_eq!(value["element"], "1");
+        assert_eq!(value["elements"][1], (F::ORDER - 1).to_string());
+        assert_eq!(from_json::<F, Mixed>(&json)?, mixed);
+
+        // Field elements must be canonical strings, and other integers must stay numbers.
+        let non_canonical = json.replace("\"1\"", &format!("\"{}\"", F::ORDER));
+        assert!(from_json::<F, Mixed>(&non_canonical).is_err());
+        let bare_number = json.replace("\"1\"", "1");
+        assert!(from_json::<F, Mixed>(&bare_number).is_err());
+        let count_string = json.replace(&u64::MAX.to_string(), &format!("\"{}\"", u64::MAX));
+        assert!(from_json::<F, Mixed>(&count_string).is_err());
+
+        Ok(())
+    }
+}
@@ -194,9 +194,10 @@
 //!         &public_inputs,
 //!         None,
 //!         &mut TimingTree::default(),
+//!         None,
 //!     ).expect("We should have a valid proof!");
 //!
-//!     verify_stark_proof(stark, proof, &CONFIG, None)
+//!     verify_stark_proof(stark, proof, &CONFIG, None, &mut TimingTree::default())
 //!         .expect("We should be able to verify this proof!")
 //! }
 //! ```
@@ -214,12 +215,15 @@
 extern crate alloc;
 
 mod get_challenges;
+#[cfg(feature = "json")]
+mod json;
 
 pub mod config;
 pub mod constraint_consumer;
 pub mod cross_table_lookup;
 pub mod evaluation_frame;
 pub mod lookup;
+pub mod periodic_column;
 pub mod proof;
 pub mod prover;
 pub mod recursive_verifier;
@@ -232,6 +236,8 @@ pub mod verifier;
 #[cfg(test)]
 pub mod fibonacci_stark;
 #[cfg(test)]
+pub mod periodic_stark;
+#[cfg(test)]
 pub mod permutation_stark;
 #[cfg(test)]
 pub mod unconstrained_stark;
@@ -0,0 +1,127 @@
+//! Support for periodic columns, i.e. fixed columns whose values repeat every `period` rows.
+//!
+//! Periodic columns are not part of the committed trace: both the prover and the verifier compute
+//! them from their description. A periodic column with values `v_0, ..., v_{k-1}` over a trace of
+//! `n` rows is the polynomial `P(X) = Q(X^{n/k})`, where `Q` interpolates the `v_j` over the
+//! subgroup of order `k`, so the verifier can evaluate it with `O(k)` work.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use plonky2::field::extension::{Extendable, FieldExtension};
+use plonky2::field::polynomial::{PolynomialCoeffs, PolynomialValues};
+use plonky2::field::types::Field;
+use plonky2::hash::hash_types::RichField;
+use plonky2::iop::ext_target::ExtensionTarget;
+use plonky2::iop::target::BoolTarget;
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::util::log2_strict;
+
+/// A fixed column whose values repeat with a power-of-two period.
+#[derive(Clone, Debug)]
+pub struct PeriodicColumn<F: Field> {
+    values: Vec<F>,
+}
+
+impl<F: Field> PeriodicColumn<F> {
+    /// Creates a periodic column repeating `values`, whose length must be a power of two.
+    pub fn new(values: Vec<F>) -> Self {
+        assert!(
+            values.len().is_power_of_two(),
+            "The period of a periodic column must be a power of two"
+        );
+        Self { values }
+    }
+
+    /// Returns the number of rows after which the values repeat.
+    pub fn period(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the values of a single period.
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    /// Returns the values of this column over a trace of `2^degree_bits` rows.
+    pub fn trace_values(&self, degree_bits: usize) -> PolynomialValues<F> {
+        let degree = 1 << degree_bits;
+        assert!(
+            self.period() <= degree,
+            "The period of a periodic column cannot exceed the trace length"
+        );
+        PolynomialValues::new(self.values.iter().copied().cycle().take(degree).collect())
+    }
+
+    /// Coefficients of the polynomial interpolating a single period over the subgroup of order
+    /// `period`.
+    fn period_coeffs(&self) -> PolynomialCoeffs<F> {
+        PolynomialValues::new(self.values.clone()).ifft()
+    }
+
+    /// Evaluates this column at `zeta` and `g * zeta`, where `g` generates the subgroup of order
+    /// `2^degree_bits`.
+    pub fn eval_local_and_next<const D: usize>(
+        &self,
+        zeta: F::Extension,
+        degree_bits: usize,
+    ) -> (F::Extension, F::Extension)
+    where
+        F: Extendable<D>,
+    {
+        let log_period = log2_strict(self.period());
+        assert!(log_period <= degree_bits);
+        let coeffs = self.period_coeffs().to_extension::<D>();
+        // `(g * zeta)^{n/k} = h * zeta^{n/k}`, where `h` generates the subgroup of order `k`.
+        let y = zeta.exp_power_of_2(degree_bits - log_period);
+        let h = F::primitive_root_of_unity(log_period);
+        (coeffs.eval(y), coeffs.eval(y.scalar_mul(h)))
+    }
+
+    /// Circuit version of `eval_local_and_next`, where the trace length `n` is given by the
+    /// little-endian bits `degree_bits_vec`.
+    pub fn eval_local_and_next_circuit<const D: usize>(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        zeta: ExtensionTarget<D>,
+        degree_bits_vec: &[BoolTarget],
+    ) -> (ExtensionTarget<D>, ExtensionTarget<D>)
+    where
+        F: RichField + Extendable<D>,
+    {
+        let log_period = log2_strict(self.period());
+        let coeffs = self.period_coeffs().coeffs;
+        // `n/k` is `n` shifted right by `log_period` bits.
+        let y = builder.exp_extension_from_bits(zeta, &degree_bits_vec[log_period..]);
+        let h = F::primitive_root_of_unity(log_period);
+        let h_y = builder.mul_const_extension(h, y);
+        let local = builder.constant_poly_eval_ext(&coeffs, y);
+        let next = builder.constant_poly_eval_ext(&coeffs, h_y);
+        (local, next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::goldilocks_field::GoldilocksField;
+    use plonky2::field::types::Sample;
+
+    use super::*;
+
+    #[test]
+    fn test_eval_local_and_next() {
+        const D: usize = 2;
+        type F = GoldilocksField;
+        type FE = <F as Extendable<D>>::Extension;
+
+        let degree_bits = 5;
+        let column = PeriodicColumn::new(F::rand_vec(4));
+        let poly = column.trace_values(degree_bits).ifft().to_extension::<D>();
+        let g = FE::primitive_root_of_unity(degree_bits);
+
+        let zeta = FE::rand();
+        let (local, next) = column.eval_local_and_next::<D>(zeta, degree_bits);
+        assert_eq!(local, poly.eval(zeta));
+        assert_eq!(next, poly.eval(g * zeta));
+    }
+}
@@ -0,0 +1,331 @@
+//! An example of proving and verifying a STARK with a periodic column.
+//! The toy STARK system adds a periodic sequence of constants to an accumulator.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use plonky2::field::extension::{Extendable, FieldExtension};
+use plonky2::field::packed::PackedField;
+use plonky2::field::polynomial::PolynomialValues;
+use plonky2::hash::hash_types::RichField;
+use plonky2::iop::ext_target::ExtensionTarget;
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+
+use crate::constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer};
+use crate::evaluation_frame::{StarkEvaluationFrame, StarkFrame};
+use crate::periodic_column::PeriodicColumn;
+use crate::stark::{Stark, TraceGenerator};
+use crate::util::trace_rows_to_poly_values;
+
+/// Toy STARK system used for testing.
+/// Computes an accumulator `x` using the state transition `x' <- x + c`, where `c` is a periodic
+/// column cycling through `constants`.
+#[derive(Copy, Clone)]
+struct PeriodicStark<F: RichField + Extendable<D>, const D: usize> {
+    num_rows: usize,
+    constants: [F; 4],
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> PeriodicStark<F, D> {
+    // The first public input is the initial value of `x`.
+    const PI_INDEX_X0: usize = 0;
+    // The second public input is the final value of `x`.
+    const PI_INDEX_RES: usize = 1;
+
+    const fn new(num_rows: usize, constants: [F; 4]) -> Self {
+        Self {
+            num_rows,
+            constants,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+const PERIODIC_COLUMNS: usize = 2;
+const PERIODIC_PUBLIC_INPUTS: usize = 2;
+
+impl<F: RichField + Extendable<D>, const D: usize> Stark<F, D> for PeriodicStark<F, D> {
+    type EvaluationFrame<FE, P, const D2: usize>
+        = StarkFrame<P, P::Scalar, PERIODIC_COLUMNS, PERIODIC_PUBLIC_INPUTS>
+    where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>;
+
+    type EvaluationFrameTarget = StarkFrame<
+        ExtensionTarget<D>,
+        ExtensionTarget<D>,
+        PERIODIC_COLUMNS,
+        PERIODIC_PUBLIC_INPUTS,
+    >;
+
+    fn eval_packed_generic<FE, P, const D2: usize>(
+        &self,
+        vars: &Self::EvaluationFrame<FE, P, D2>,
+        yield_constr: &mut ConstraintConsumer<P>,
+    ) where
+        FE: FieldExtension<D2, BaseField = F>,
+        P: PackedField<Scalar = FE>,
+    {
+        let local_values = vars.get_local_values();
+        let next_values = vars.get_next_values();
+        let public_inputs = vars.get_public_inputs();
+
+        // Check public inputs.
+        yield_constr.constraint_first_row(local_values[0] - public_inputs[Self::PI_INDEX_X0]);
+        yield_constr.constraint_last_row(local_values[0] - public_inputs[Self::PI_INDEX_RES]);
+
+        // x' <- x + c
+        yield_constr.constraint_transition(next_values[0] - local_values[0] - local_values[1]);
+    }
+
+    fn eval_ext_circuit(
+        &self,
+        builder: &mut CircuitBuilder<F, D>,
+        vars: &Self::EvaluationFrameTarget,
+        yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+    ) {
+        let local_values = vars.get_local_values();
+        let next_values = vars.get_next_values();
+        let public_inputs = vars.get_public_inputs();
+        // Check public inputs.
+        let pis_constraints = [
+            builder.sub_extension(local_values[0], public_inputs[Self::PI_INDEX_X0]),
+            builder.sub_extension(local_values[0], public_inputs[Self::PI_INDEX_RES]),
+        ];
+        yield_constr.constraint_first_row(builder, pis_constraints[0]);
+        yield_constr.constraint_last_row(builder, pis_constraints[1]);
+
+        // x' <- x + c
+        let constraint = {
+            let tmp = builder.sub_extension(next_values[0], local_values[0]);
+            builder.sub_extension(tmp, local_values[1])
+        };
+        yield_constr.constraint_transition(builder, constraint);
+    }
+
+    fn constraint_degree(&self) -> usize {
+        2
+    }
+
+    fn periodic_columns(&self) -> Vec<PeriodicColumn<F>> {
+        vec![PeriodicColumn::new(self.constants.to_vec())]
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> TraceGenerator<F, D> for PeriodicStark<F, D> {
+    /// The initial value of `x`.
+    type TraceInputs = F;
+
+    /// Generate the committed trace, i.e. the `x` column only.
+    fn generate_trace(&self, inputs: &Self::TraceInputs) -> Vec<PolynomialValues<F>> {
+        let trace_rows = self
+            .constants
+            .iter()
+            .cycle()
+            .take(self.num_rows)
+            .scan(*inputs, |acc, &c| {
+                let tmp = *acc;
+                *acc += c;
+                Some([tmp])
+            })
+            .collect::<Vec<_>>();
+        trace_rows_to_poly_values(trace_rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use plonky2::field::extension::Extendable;
+    use plonky2::field::types::{Field, Sample};
+    use plonky2::hash::hash_types::RichField;
+    use plonky2::iop::witness::PartialWitness;
+    use plonky2::plonk::circuit_builder::CircuitBuilder;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::config::{AlgebraicHasher, GenericConfig, PoseidonGoldilocksConfig};
+    use plonky2::util::timing::TimingTree;
+
+    use crate::config::StarkConfig;
+    use crate::periodic_stark::PeriodicStark;
+    use crate::proof::StarkProofWithPublicInputs;
+    use crate::prover::prove;
+    use crate::recursive_verifier::{
+        add_virtual_stark_proof_with_pis, set_stark_proof_with_pis_target,
+        verify_stark_proof_circuit,
+    };
+    use crate::stark::{Stark, TraceGenerator};
+    use crate::stark_testing::{test_stark_circuit_constraints, test_stark_low_degree};
+    use crate::verifier::verify_stark_proof;
+
+    fn constants<F: Field>() -> [F; 4] {
+        [
+            F::from_canonical_u64(1),
+            F::from_canonical_u64(2),
+            F::from_canonical_u64(3),
+            F::from_canonical_u64(4),
+        ]
+    }
+
+    #[test]
+    fn test_periodic_stark() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type S = PeriodicStark<F, D>;
+
+        let config = StarkConfig::standard_fast_config();
+        let num_rows = 1 << 5;
+        // Each period adds `1 + 2 + 3 + 4 = 10`, and the last row has seen all but one constant.
+        let public_inputs = [F::ONE, F::from_canonical_u64(1 + 10 * 8 - 4)];
+
+        let stark = S::new(num_rows, constants());
+        let trace = stark.generate_trace(&public_inputs[0]);
+        let proof = prove::<F, C, S, D>(
+            stark,
+            &config,
+            trace,
+            &public_inputs,
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
+    }
+
+    #[test]
+    fn test_periodic_stark_wrong_constants() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type S = PeriodicStark<F, D>;
+
+        let config = StarkConfig::standard_fast_config();
+        let num_rows = 1 << 5;
+        let public_inputs = [F::ONE, F::from_canonical_u64(1 + 10 * 8 - 4)];
+
+        let stark = S::new(num_rows, constants());
+        let trace = stark.generate_trace(&public_inputs[0]);
+        let proof = prove::<F, C, S, D>(
+            stark,
+            &config,
+            trace,
+            &public_inputs,
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+
+        // The verifier derives the periodic column itself, so a different column must be rejected.
+        let mut wrong_constants = constants::<F>();
+        wrong_constants.swap(0, 1);
+        let wrong_stark = S::new(num_rows, wrong_constants);
+        assert!(verify_stark_proof(
+            wrong_stark,
+            proof,
+            &config,
+            None,
+            &mut TimingTree::default()
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_periodic_stark_degree() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type S = PeriodicStark<F, D>;
+
+        let num_rows = 1 << 5;
+        let stark = S::new(num_rows, F::rand_array());
+        test_stark_low_degree(stark)
+    }
+
+    #[test]
+    fn test_periodic_stark_circuit() -> Result<()> {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type S = PeriodicStark<F, D>;
+
+        let num_rows = 1 << 5;
+        let stark = S::new(num_rows, F::rand_array());
+        test_stark_circuit_constraints::<F, C, S, D>(stark)
+    }
+
+    #[test]
+    fn test_recursive_stark_verifier() -> Result<()> {
+        init_logger();
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        type S = PeriodicStark<F, D>;
+
+        let config = StarkConfig::standard_fast_config();
+        let num_rows = 1 << 5;
+        let public_inputs = [F::ONE, F::from_canonical_u64(1 + 10 * 8 - 4)];
+
+        let stark = S::new(num_rows, constants());
+        let trace = stark.generate_trace(&public_inputs[0]);
+        let proof = prove::<F, C, S, D>(
+            stark,
+            &config,
+            trace,
+            &public_inputs,
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+        verify_stark_proof(
+            stark,
+            proof.clone(),
+            &config,
+            None,
+            &mut TimingTree::default(),
+        )?;
+
+        recursive_proof::<F, C, S, C, D>(stark, proof, &config, true)
+    }
+
+    fn recursive_proof<
+        F: RichField + Extendable<D>,
+        C: GenericConfig<D, F = F>,
+        S: Stark<F, D> + Copy,
+        InnerC: GenericConfig<D, F = F>,
+        const D: usize,
+    >(
+        stark: S,
+        inner_proof: StarkProofWithPublicInputs<F, InnerC, D>,
+        inner_config: &StarkConfig,
+        print_gate_counts: bool,
+    ) -> Result<()>
+    where
+        InnerC::Hasher: AlgebraicHasher<F>,
+    {
+        let circuit_config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(circuit_config);
+        let mut pw = PartialWitness::new();
+        let degree_bits = inner_proof.proof.recover_degree_bits(inner_config);
+        let pt =
+            add_virtual_stark_proof_with_pis(&mut builder, &stark, inner_config, degree_bits, 0, 0);
+        set_stark_proof_with_pis_target(&mut pw, &pt, &inner_proof, degree_bits, builder.zero())?;
+
+        verify_stark_proof_circuit::<F, InnerC, S, D>(&mut builder, stark, pt, inner_config, None);
+
+        if print_gate_counts {
+            builder.print_gate_counts(0);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw)?;
+        data.verify(proof)
+    }
+
+    fn init_logger() {
+        let _ = env_logger::builder().format_timestamp(None).try_init();
+    }
+}
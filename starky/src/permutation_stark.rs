@@ -143,9 +143,10 @@ mod tests {
             &[public_input],
             None,
             &mut TimingTree::default(),
+            None,
         )?;
 
-        verify_stark_proof(stark, proof, &config, None)
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
     }
 
     #[test]
@@ -193,8 +194,15 @@ mod tests {
             &[public_input],
             None,
             &mut TimingTree::default(),
+            None,
+        )?;
+        verify_stark_proof(
+            stark,
+            proof.clone(),
+            &config,
+            None,
+            &mut TimingTree::default(),
         )?;
-        verify_stark_proof(stark, proof.clone(), &config, None)?;
 
         recursive_proof::<F, C, S, C, D>(stark, proof, &config, true)
     }
@@ -4,28 +4,38 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
+use core::iter::once;
 
+use anyhow::{ensure, Result};
 use itertools::Itertools;
 use plonky2::field::extension::{Extendable, FieldExtension};
 use plonky2::fri::oracle::PolynomialBatch;
-use plonky2::fri::proof::{FriChallenges, FriChallengesTarget, FriProof, FriProofTarget};
+use plonky2::fri::proof::{
+    CompressedFriProof, FriChallenges, FriChallengesTarget, FriProof, FriProofTarget,
+};
 use plonky2::fri::structure::{
     FriOpeningBatch, FriOpeningBatchTarget, FriOpenings, FriOpeningsTarget,
 };
+use plonky2::fri::FriParams;
 use plonky2::hash::hash_types::{MerkleCapTarget, RichField};
 use plonky2::hash::merkle_tree::MerkleCap;
+use plonky2::iop::challenger::Challenger;
 use plonky2::iop::ext_target::ExtensionTarget;
 use plonky2::iop::target::Target;
 use plonky2::plonk::config::{GenericConfig, Hasher};
+use plonky2::plonk::proof::FriInferredElements;
 use plonky2::util::serialization::{Buffer, IoResult, Read, Write};
 use plonky2_maybe_rayon::*;
 use serde::{Deserialize, Serialize};
 
 use crate::config::StarkConfig;
+#[cfg(feature = "json")]
+use crate::json::{from_json, to_json};
 use crate::lookup::GrandProductChallengeSet;
+use crate::stark::Stark;
 
 /// Merkle caps and openings that form the proof of a single STARK.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(bound = "")]
 pub struct StarkProof<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize> {
     /// Merkle cap of LDEs of trace values.
@@ -50,6 +60,182 @@ impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize> S
         let lde_bits = config.fri_config.cap_height + initial_merkle_proof.siblings.len();
         lde_bits - config.fri_config.rate_bits
     }
+
+    /// Compresses the proof, deduplicating the FRI query openings that share the same indices.
+    pub fn compress(self, indices: &[usize], params: &FriParams) -> CompressedStarkProof<F, C, D> {
+        let StarkProof {
+            trace_cap,
+            auxiliary_polys_cap,
+            quotient_polys_cap,
+            openings,
+            opening_proof,
+        } = self;
+
+        CompressedStarkProof {
+            trace_cap,
+            auxiliary_polys_cap,
+            quotient_polys_cap,
+            openings,
+            opening_proof: opening_proof.compress(indices, params),
+        }
+    }
+
+    /// Serializes the proof. Optional caps and openings are skipped when absent, as their
+    /// presence is determined by the STARK being proven.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.to_buffer(&mut buffer)
+            .expect("Writing to a byte-vector cannot fail.");
+        buffer
+    }
+
+    fn to_buffer(&self, buffer: &mut Vec<u8>) -> IoResult<()> {
+        buffer.write_merkle_cap(&self.trace_cap)?;
+        if let Some(cap) = &self.auxiliary_polys_cap {
+            buffer.write_merkle_cap(cap)?;
+        }
+        if let Some(cap) = &self.quotient_polys_cap {
+            buffer.write_merkle_cap(cap)?;
+        }
+        self.openings.to_buffer(buffer)?;
+        buffer.write_fri_proof::<F, C, D>(&self.opening_proof)
+    }
+
+    /// Computes a digest of the serialized proof, as given by [`StarkProof::to_bytes`], so that
+    /// identical proofs can be recognized without comparing them in full.
+    pub fn stark_proof_hash<H: Hasher<F>>(&self) -> H::Hash {
+        // Pack the bytes into field elements, 4 bytes at a time so that each packed value is
+        // canonical. The byte length is prepended to make the packing injective.
+        let bytes = self.to_bytes();
+        let elements = once(F::from_canonical_usize(bytes.len()))
+            .chain(bytes.chunks(4).map(|chunk| {
+                let mut word = [0; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                F::from_canonical_u32(u32::from_le_bytes(word))
+            }))
+            .collect::<Vec<_>>();
+        H::hash_no_pad(&elements)
+    }
+
+    /// Returns the number of bytes taken by each section of the serialized proof, as given by
+    /// [`StarkProof::to_bytes`].
+    pub fn size_breakdown(&self) -> StarkProofSizes {
+        fn size_of(write: impl FnOnce(&mut Vec<u8>) -> IoResult<()>) -> usize {
+            let mut buffer = Vec::new();
+            write(&mut buffer).expect("Writing to a byte-vector cannot fail.");
+            buffer.len()
+        }
+
+        let opening_proof = &self.opening_proof;
+        StarkProofSizes {
+            trace_cap: size_of(|b| b.write_merkle_cap(&self.trace_cap)),
+            auxiliary_polys_cap: self
+                .auxiliary_polys_cap
+                .as_ref()
+                .map_or(0, |cap| size_of(|b| b.write_merkle_cap(cap))),
+            quotient_polys_cap: self
+                .quotient_polys_cap
+                .as_ref()
+                .map_or(0, |cap| size_of(|b| b.write_merkle_cap(cap))),
+            openings: size_of(|b| self.openings.to_buffer(b)),
+            fri_commit_phase_caps: size_of(|b| {
+                opening_proof
+                    .commit_phase_merkle_caps
+                    .iter()
+                    .try_for_each(|cap| b.write_merkle_cap(cap))
+            }),
+            fri_query_rounds: size_of(|b| {
+                b.write_fri_query_rounds::<F, C, D>(&opening_proof.query_round_proofs)
+            }),
+            fri_final_poly: size_of(|b| {
+                b.write_field_ext_vec::<F, D>(&opening_proof.final_poly.coeffs)
+            }),
+            fri_pow_witness: size_of(|b| b.write_field(opening_proof.pow_witness)),
+        }
+    }
+}
+
+/// Number of bytes taken by each section of a serialized [`StarkProof`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StarkProofSizes {
+    /// Merkle cap of the trace LDEs.
+    pub trace_cap: usize,
+    /// Merkle cap of the lookup and cross-table lookup `Z` polynomial LDEs.
+    pub auxiliary_polys_cap: usize,
+    /// Merkle cap of the quotient polynomial LDEs.
+    pub quotient_polys_cap: usize,
+    /// Openings of all polynomials at the challenge point.
+    pub openings: usize,
+    /// Merkle caps of the FRI commit phase.
+    pub fri_commit_phase_caps: usize,
+    /// FRI query round proofs.
+    pub fri_query_rounds: usize,
+    /// Coefficients of the final FRI polynomial.
+    pub fri_final_poly: usize,
+    /// FRI proof-of-work witness.
+    pub fri_pow_witness: usize,
+}
+
+impl StarkProofSizes {
+    /// Total number of bytes of the serialized proof.
+    pub const fn total(&self) -> usize {
+        self.trace_cap
+            + self.auxiliary_polys_cap
+            + self.quotient_polys_cap
+            + self.openings
+            + self.fri_commit_phase_caps
+            + self.fri_query_rounds
+            + self.fri_final_poly
+            + self.fri_pow_witness
+    }
+}
+
+/// A [`StarkProof`] whose FRI query round proofs have been compressed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct CompressedStarkProof<
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
+> {
+    /// Merkle cap of LDEs of trace values.
+    pub trace_cap: MerkleCap<F, C::Hasher>,
+    /// Optional merkle cap of LDEs of permutation Z values, if any.
+    pub auxiliary_polys_cap: Option<MerkleCap<F, C::Hasher>>,
+    /// Merkle cap of LDEs of trace values.
+    pub quotient_polys_cap: Option<MerkleCap<F, C::Hasher>>,
+    /// Purported values of each polynomial at the challenge point.
+    pub openings: StarkOpeningSet<F, D>,
+    /// A compressed batch FRI argument for all openings.
+    pub opening_proof: CompressedFriProof<F, C::Hasher, D>,
+}
+
+impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
+    CompressedStarkProof<F, C, D>
+{
+    /// Decompresses the proof, reinserting the FRI query openings that were deduplicated.
+    pub fn decompress(
+        self,
+        challenges: &FriChallenges<F, D>,
+        fri_inferred_elements: FriInferredElements<F, D>,
+        params: &FriParams,
+    ) -> StarkProof<F, C, D> {
+        let CompressedStarkProof {
+            trace_cap,
+            auxiliary_polys_cap,
+            quotient_polys_cap,
+            openings,
+            opening_proof,
+        } = self;
+
+        StarkProof {
+            trace_cap,
+            auxiliary_polys_cap,
+            quotient_polys_cap,
+            openings,
+            opening_proof: opening_proof.decompress(challenges, fri_inferred_elements, params),
+        }
+    }
 }
 
 /// Circuit version of [`StarkProof`].
@@ -127,7 +313,7 @@ impl<const D: usize> StarkProofTarget<D> {
 }
 
 /// Merkle caps and openings that form the proof of a single STARK, along with its public inputs.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(bound = "")]
 pub struct StarkProofWithPublicInputs<
     F: RichField + Extendable<D>,
@@ -141,6 +327,110 @@ pub struct StarkProofWithPublicInputs<
     pub public_inputs: Vec<F>,
 }
 
+impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
+    StarkProofWithPublicInputs<F, C, D>
+{
+    /// Compresses the proof of a single STARK.
+    ///
+    /// Only proofs generated without custom `verifier_circuit_fri_params` are supported.
+    pub fn compress(self, config: &StarkConfig) -> CompressedStarkProofWithPublicInputs<F, C, D> {
+        let degree_bits = self.proof.recover_degree_bits(config);
+        let mut challenger = Challenger::<F, C::Hasher>::new();
+        let challenges = self.get_challenges(&mut challenger, None, false, config, None);
+        let compressed_proof = self.proof.compress(
+            &challenges.fri_challenges.fri_query_indices,
+            &config.fri_params(degree_bits),
+        );
+
+        CompressedStarkProofWithPublicInputs {
+            proof: compressed_proof,
+            public_inputs: self.public_inputs,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize> StarkProof<F, C, D> {
+    /// Serializes this proof to JSON, with field elements rendered as canonical decimal strings.
+    pub fn to_json(&self) -> Result<String> {
+        to_json::<F, _>(self)
+    }
+
+    /// Deserializes a proof from the JSON produced by [`StarkProof::to_json`].
+    pub fn from_json(json: &str) -> Result<Self> {
+        from_json::<F, _>(json)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
+    StarkProofWithPublicInputs<F, C, D>
+{
+    /// Serializes this proof and its public inputs to JSON, with field elements rendered as
+    /// canonical decimal strings.
+    pub fn to_json(&self) -> Result<String> {
+        to_json::<F, _>(self)
+    }
+
+    /// Deserializes a proof from the JSON produced by [`StarkProofWithPublicInputs::to_json`].
+    pub fn from_json(json: &str) -> Result<Self> {
+        from_json::<F, _>(json)
+    }
+}
+
+/// A [`CompressedStarkProof`] along with its public inputs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct CompressedStarkProofWithPublicInputs<
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    const D: usize,
+> {
+    /// A compressed STARK proof.
+    pub proof: CompressedStarkProof<F, C, D>,
+    /// Public inputs associated to this STARK proof.
+    pub public_inputs: Vec<F>,
+}
+
+impl<F: RichField + Extendable<D>, C: GenericConfig<D, F = F>, const D: usize>
+    CompressedStarkProofWithPublicInputs<F, C, D>
+{
+    /// Decompresses the proof of a single STARK, whose trace has length `2^degree_bits`.
+    pub fn decompress<S: Stark<F, D>>(
+        self,
+        stark: &S,
+        config: &StarkConfig,
+        degree_bits: usize,
+    ) -> Result<StarkProofWithPublicInputs<F, C, D>> {
+        ensure!(self.public_inputs.len() == S::PUBLIC_INPUTS);
+        let mut challenger = Challenger::<F, C::Hasher>::new();
+        let challenges = self.get_challenges(&mut challenger, config, degree_bits);
+        let fri_params = config.fri_params(degree_bits);
+        let fri_inferred_elements = self.proof.opening_proof.get_inferred_elements::<C>(
+            &stark.fri_instance(
+                challenges.stark_zeta,
+                F::primitive_root_of_unity(degree_bits),
+                0,
+                vec![],
+                config,
+            ),
+            &self.proof.openings.to_fri_openings(),
+            &challenges.fri_challenges,
+            &fri_params,
+        );
+        let decompressed_proof = self.proof.decompress(
+            &challenges.fri_challenges,
+            fri_inferred_elements,
+            &fri_params,
+        );
+
+        Ok(StarkProofWithPublicInputs {
+            proof: decompressed_proof,
+            public_inputs: self.public_inputs,
+        })
+    }
+}
+
 /// Circuit version of [`StarkProofWithPublicInputs`].
 #[derive(Debug, Clone)]
 pub struct StarkProofWithPublicInputsTarget<const D: usize> {
@@ -200,7 +490,7 @@ pub struct MultiProofChallenges<F: RichField + Extendable<D>, const D: usize, co
 }
 
 /// Purported values of each polynomial at the challenge point.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(bound = "")]
 pub struct StarkOpeningSet<F: RichField + Extendable<D>, const D: usize> {
     /// Openings of trace polynomials at `zeta`.
@@ -304,6 +594,25 @@ impl<F: RichField + Extendable<D>, const D: usize> StarkOpeningSet<F, D> {
 
         FriOpenings { batches }
     }
+
+    /// Serializes the opening set. Absent openings are skipped.
+    fn to_buffer(&self, buffer: &mut Vec<u8>) -> IoResult<()> {
+        buffer.write_field_ext_vec::<F, D>(&self.local_values)?;
+        buffer.write_field_ext_vec::<F, D>(&self.next_values)?;
+        if let Some(poly) = &self.auxiliary_polys {
+            buffer.write_field_ext_vec::<F, D>(poly)?;
+        }
+        if let Some(poly_next) = &self.auxiliary_polys_next {
+            buffer.write_field_ext_vec::<F, D>(poly_next)?;
+        }
+        if let Some(ctl_zs_first) = &self.ctl_zs_first {
+            buffer.write_field_vec(ctl_zs_first)?;
+        }
+        if let Some(quotient_polys) = &self.quotient_polys {
+            buffer.write_field_ext_vec::<F, D>(quotient_polys)?;
+        }
+        Ok(())
+    }
 }
 
 /// Circuit version of [`StarkOpeningSet`].
@@ -2,7 +2,9 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 use core::iter::once;
+use core::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{ensure, Result};
 use itertools::Itertools;
@@ -17,11 +19,12 @@ use plonky2::fri::prover::final_poly_coeff_len;
 use plonky2::fri::reduction_strategies::FriReductionStrategy;
 use plonky2::fri::FriParams;
 use plonky2::hash::hash_types::RichField;
+use plonky2::hash::merkle_tree::MerkleTree;
 use plonky2::iop::challenger::Challenger;
 use plonky2::plonk::config::GenericConfig;
 use plonky2::timed;
 use plonky2::util::timing::TimingTree;
-use plonky2::util::{log2_ceil, log2_strict, transpose};
+use plonky2::util::{log2_ceil, log2_strict, reverse_index_bits_in_place, transpose};
 use plonky2_maybe_rayon::*;
 
 use crate::config::StarkConfig;
@@ -33,10 +36,39 @@ use crate::lookup::{
     LookupCheckVars,
 };
 use crate::proof::{StarkOpeningSet, StarkProof, StarkProofWithPublicInputs};
-use crate::stark::Stark;
+use crate::stark::{Stark, TraceGenerator};
 use crate::vanishing_poly::eval_vanishing_poly;
 
+/// Errors that can occur while generating a STARK proof.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProverError {
+    /// The abort signal was set before the proof was complete.
+    Aborted,
+}
+
+impl Display for ProverError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Aborted => write!(f, "Proving was aborted"),
+        }
+    }
+}
+
+impl core::error::Error for ProverError {}
+
+/// Returns [`ProverError::Aborted`] if `abort` is set. Called between the proving phases, so that
+/// a cancelled proof stops without running to completion.
+pub fn check_abort_signal(abort: Option<&AtomicBool>) -> Result<()> {
+    if abort.is_some_and(|abort| abort.load(Ordering::Relaxed)) {
+        return Err(ProverError::Aborted.into());
+    }
+    Ok(())
+}
+
 /// From a STARK trace, computes a STARK proof to attest its correctness.
+///
+/// If `abort` is set during proving, this returns [`ProverError::Aborted`] at the next phase
+/// boundary.
 pub fn prove<F, C, S, const D: usize>(
     stark: S,
     config: &StarkConfig,
@@ -44,6 +76,7 @@ pub fn prove<F, C, S, const D: usize>(
     public_inputs: &[F],
     verifier_circuit_fri_params: Option<FriParams>,
     timing: &mut TimingTree,
+    abort: Option<&AtomicBool>,
 ) -> Result<StarkProofWithPublicInputs<F, C, D>>
 where
     F: RichField + Extendable<D>,
@@ -80,6 +113,7 @@ where
             (None, None)
         };
 
+    check_abort_signal(abort)?;
     let trace_commitment = timed!(
         timing,
         "compute trace commitment",
@@ -109,6 +143,35 @@ where
         final_poly_coeff_len,
         max_num_query_steps,
         timing,
+        abort,
+    )
+}
+
+/// Generates the trace of a STARK from the given inputs, and computes a STARK proof to attest its
+/// correctness.
+pub fn prove_from_inputs<F, C, S, const D: usize>(
+    stark: S,
+    config: &StarkConfig,
+    trace_inputs: &S::TraceInputs,
+    public_inputs: &[F],
+    verifier_circuit_fri_params: Option<FriParams>,
+    timing: &mut TimingTree,
+    abort: Option<&AtomicBool>,
+) -> Result<StarkProofWithPublicInputs<F, C, D>>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    S: TraceGenerator<F, D>,
+{
+    let trace_poly_values = timed!(timing, "generate trace", stark.generate_trace(trace_inputs));
+    prove(
+        stark,
+        config,
+        trace_poly_values,
+        public_inputs,
+        verifier_circuit_fri_params,
+        timing,
+        abort,
     )
 }
 
@@ -119,6 +182,10 @@ where
 /// - all the required polynomial and FRI argument openings.
 /// - individual `ctl_data` and common `ctl_challenges` if the STARK is part
 ///   of a multi-STARK system.
+///
+/// Callers that build their own transcript can start it with `Challenger::new_with_domain`,
+/// keyed by the identity of the STARK, so that it can't collide with the transcript of another
+/// protocol. The verifier must then start from the same domain.
 pub fn prove_with_commitment<F, C, S, const D: usize>(
     stark: &S,
     config: &StarkConfig,
@@ -131,6 +198,7 @@ pub fn prove_with_commitment<F, C, S, const D: usize>(
     final_poly_coeff_len: Option<usize>,
     max_num_query_steps: Option<usize>,
     timing: &mut TimingTree,
+    abort: Option<&AtomicBool>,
 ) -> Result<StarkProofWithPublicInputs<F, C, D>>
 where
     F: RichField + Extendable<D>,
@@ -153,6 +221,8 @@ where
         "The degree of the Stark constraints must be <= blowup_factor + 1"
     );
 
+    check_abort_signal(abort)?;
+
     // Permutation arguments.
     let lookup_challenges = stark.uses_lookups().then(|| {
         if let Some(c) = ctl_challenges {
@@ -228,6 +298,7 @@ where
         challenger.observe_cap(cap);
     }
 
+    check_abort_signal(abort)?;
     let alphas = challenger.get_n_challenges(config.num_challenges);
 
     let num_ctl_polys = ctl_data
@@ -270,6 +341,7 @@ where
             config,
         )
     );
+    check_abort_signal(abort)?;
     let (quotient_commitment, quotient_polys_cap) = if let Some(quotient_polys) = quotient_polys {
         let all_quotient_chunks = timed!(
             timing,
@@ -333,6 +405,7 @@ where
     // Get the FRI openings and observe them.
     challenger.observe_openings(&openings.to_fri_openings());
 
+    check_abort_signal(abort)?;
     let initial_merkle_trees = once(trace_commitment)
         .chain(&auxiliary_polys_commitment)
         .chain(&quotient_commitment)
@@ -366,6 +439,76 @@ where
     })
 }
 
+/// Commits to a trace one column at a time, so that the full trace never has to be held in
+/// memory alongside its LDE. The resulting [`PolynomialBatch`] is identical to the one obtained
+/// by committing all columns at once with [`PolynomialBatch::from_values`], without blinding.
+#[derive(Debug)]
+pub struct TraceCommitter<F: RichField> {
+    degree_bits: usize,
+    rate_bits: usize,
+    cap_height: usize,
+    polynomials: Vec<PolynomialCoeffs<F>>,
+    lde_values: Vec<Vec<F>>,
+}
+
+impl<F: RichField> TraceCommitter<F> {
+    /// Creates a new committer for columns of length `2^degree_bits`.
+    pub fn new(degree_bits: usize, config: &StarkConfig) -> Self {
+        Self {
+            degree_bits,
+            rate_bits: config.fri_config.rate_bits,
+            cap_height: config.fri_config.cap_height,
+            polynomials: Vec::new(),
+            lde_values: Vec::new(),
+        }
+    }
+
+    /// Interpolates `column` and computes its LDE, after which the column itself can be dropped.
+    pub fn add_column(&mut self, column: PolynomialValues<F>) {
+        assert_eq!(
+            column.len(),
+            1 << self.degree_bits,
+            "Column length inconsistent with degree_bits"
+        );
+        let coeffs = column.ifft();
+        let lde = coeffs
+            .lde(self.rate_bits)
+            .coset_fft_with_options(F::coset_shift(), Some(self.rate_bits), None)
+            .values;
+        self.polynomials.push(coeffs);
+        self.lde_values.push(lde);
+    }
+
+    /// Returns the number of columns added so far.
+    pub fn num_columns(&self) -> usize {
+        self.polynomials.len()
+    }
+
+    /// Builds the Merkle tree over the LDEs of all added columns.
+    pub fn finalize<C, const D: usize>(self, timing: &mut TimingTree) -> PolynomialBatch<F, C, D>
+    where
+        F: Extendable<D>,
+        C: GenericConfig<D, F = F>,
+    {
+        assert!(self.num_columns() > 0, "No columns were added");
+        let mut leaves = timed!(timing, "transpose LDEs", transpose(&self.lde_values));
+        reverse_index_bits_in_place(&mut leaves);
+        let merkle_tree = timed!(
+            timing,
+            "build Merkle tree",
+            MerkleTree::new(leaves, self.cap_height)
+        );
+
+        PolynomialBatch {
+            polynomials: self.polynomials,
+            merkle_tree,
+            degree_log: self.degree_bits,
+            rate_bits: self.rate_bits,
+            blinding: false,
+        }
+    }
+}
+
 /// Computes the quotient polynomials `(sum alpha^i C_i(x)) / Z_H(x)` for `alpha` in `alphas`,
 /// where the `C_i`s are the STARK constraints.
 fn compute_quotient_polys<'a, F, P, C, S, const D: usize>(
@@ -413,9 +556,32 @@ where
 
     let z_h_on_coset = ZeroPolyOnCoset::<F>::new(degree_bits, quotient_degree_bits);
 
-    // Retrieve the LDE values at index `i`.
-    let get_trace_values_packed =
-        |i_start| -> Vec<P> { trace_commitment.get_lde_values_packed(i_start, step) };
+    // Periodic columns are not committed, so we compute their LDEs on the quotient coset directly.
+    let periodic_ldes = stark
+        .periodic_columns()
+        .iter()
+        .map(|column| {
+            column
+                .trace_values(degree_bits)
+                .lde_onto_coset(quotient_degree_bits)
+                .values
+        })
+        .collect::<Vec<_>>();
+
+    // Retrieve the LDE values at index `i`, followed by the periodic column values.
+    let get_trace_values_packed = |i_start| -> Vec<P> {
+        let mut values = trace_commitment.get_lde_values_packed(i_start, step);
+        values.extend(periodic_ldes.iter().map(|lde| {
+            let mut packed = P::ZEROS;
+            packed
+                .as_slice_mut()
+                .iter_mut()
+                .enumerate()
+                .for_each(|(j, packed_j)| *packed_j = lde[(i_start + j) % lde.len()]);
+            packed
+        }));
+        values
+    };
 
     // Last element of the subgroup.
     let last = F::primitive_root_of_unity(degree_bits).inverse();
@@ -606,7 +772,15 @@ fn check_constraints<'a, F, C, S, const D: usize>(
     };
 
     // Get batch evaluations of the trace and permutation polynomials over our subgroup.
-    let trace_subgroup_evals = get_subgroup_evals(trace_commitment);
+    let mut trace_subgroup_evals = get_subgroup_evals(trace_commitment);
+    // Periodic columns are not committed, so we append their values to each row.
+    for column in stark.periodic_columns() {
+        let values = column.trace_values(degree_bits).lde(rate_bits).values;
+        trace_subgroup_evals
+            .iter_mut()
+            .zip(values)
+            .for_each(|(row, value)| row.push(value));
+    }
     let auxiliary_subgroup_evals = auxiliary_commitment.as_ref().map(get_subgroup_evals);
 
     // Last element of the subgroup.
@@ -699,3 +873,123 @@ fn check_constraints<'a, F, C, S, const D: usize>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::extension::FieldExtension;
+    use plonky2::field::types::Sample;
+    use plonky2::iop::ext_target::ExtensionTarget;
+    use plonky2::plonk::circuit_builder::CircuitBuilder;
+    use plonky2::plonk::config::PoseidonGoldilocksConfig;
+
+    use super::*;
+    use crate::constraint_consumer::RecursiveConstraintConsumer;
+    use crate::evaluation_frame::StarkFrame;
+
+    /// A STARK with a constant column, which raises `abort` as soon as its constraints are
+    /// evaluated, i.e. midway through proving.
+    struct AbortingStark<'a> {
+        abort: &'a AtomicBool,
+    }
+
+    impl<F: RichField + Extendable<D>, const D: usize> Stark<F, D> for AbortingStark<'_> {
+        type EvaluationFrame<FE, P, const D2: usize>
+            = StarkFrame<P, P::Scalar, 1, 0>
+        where
+            FE: FieldExtension<D2, BaseField = F>,
+            P: PackedField<Scalar = FE>;
+
+        type EvaluationFrameTarget = StarkFrame<ExtensionTarget<D>, ExtensionTarget<D>, 1, 0>;
+
+        fn eval_packed_generic<FE, P, const D2: usize>(
+            &self,
+            vars: &Self::EvaluationFrame<FE, P, D2>,
+            yield_constr: &mut ConstraintConsumer<P>,
+        ) where
+            FE: FieldExtension<D2, BaseField = F>,
+            P: PackedField<Scalar = FE>,
+        {
+            self.abort.store(true, Ordering::Relaxed);
+            yield_constr
+                .constraint_transition(vars.get_next_values()[0] - vars.get_local_values()[0]);
+        }
+
+        fn eval_ext_circuit(
+            &self,
+            builder: &mut CircuitBuilder<F, D>,
+            vars: &Self::EvaluationFrameTarget,
+            yield_constr: &mut RecursiveConstraintConsumer<F, D>,
+        ) {
+            let diff = builder.sub_extension(vars.get_next_values()[0], vars.get_local_values()[0]);
+            yield_constr.constraint_transition(builder, diff);
+        }
+
+        fn constraint_degree(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn test_prove_abort_signal() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = StarkConfig::standard_fast_config();
+        let prove_with = |abort: &AtomicBool| {
+            let stark = AbortingStark { abort };
+            let trace = vec![PolynomialValues::constant(F::TWO, 1 << 5)];
+            prove::<F, C, _, D>(
+                stark,
+                &config,
+                trace,
+                &[],
+                None,
+                &mut TimingTree::default(),
+                Some(abort),
+            )
+        };
+
+        // Constraint evaluation raises the signal, so the next phase boundary aborts.
+        let abort = AtomicBool::new(false);
+        let err = prove_with(&abort).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&ProverError::Aborted));
+
+        // An already raised signal stops the prover before it does any work.
+        let err = prove_with(&AtomicBool::new(true)).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&ProverError::Aborted));
+    }
+
+    #[test]
+    fn test_trace_committer_matches_batch_commitment() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = StarkConfig::standard_fast_config();
+        let degree_bits = 5;
+        let columns = (0..10)
+            .map(|_| PolynomialValues::new(F::rand_vec(1 << degree_bits)))
+            .collect::<Vec<_>>();
+
+        let mut timing = TimingTree::default();
+        let batch = PolynomialBatch::<F, C, D>::from_values(
+            columns.clone(),
+            config.fri_config.rate_bits,
+            false,
+            config.fri_config.cap_height,
+            &mut timing,
+            None,
+        );
+
+        let mut committer = TraceCommitter::new(degree_bits, &config);
+        for column in columns {
+            committer.add_column(column);
+        }
+        assert_eq!(committer.num_columns(), 10);
+        let incremental = committer.finalize::<C, D>(&mut timing);
+
+        assert_eq!(incremental.merkle_tree.cap, batch.merkle_tree.cap);
+        assert_eq!(incremental, batch);
+    }
+}
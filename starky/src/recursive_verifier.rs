@@ -8,7 +8,7 @@ use core::iter::once;
 use anyhow::{ensure, Result};
 use itertools::Itertools;
 use plonky2::field::extension::Extendable;
-use plonky2::fri::witness_util::set_fri_proof_target;
+use plonky2::fri::witness_util::set_fri_proof_target_allowing_extra_query_rounds;
 use plonky2::hash::hash_types::RichField;
 use plonky2::iop::challenger::RecursiveChallenger;
 use plonky2::iop::ext_target::ExtensionTarget;
@@ -33,6 +33,9 @@ use crate::vanishing_poly::eval_vanishing_poly_circuit;
 
 /// Encodes the verification of a [`StarkProofWithPublicInputsTarget`]
 /// for some statement in a circuit.
+///
+/// Returns the public input targets of the verified proof, so that they can be connected to the
+/// outer circuit's logic.
 pub fn verify_stark_proof_circuit<
     F: RichField + Extendable<D>,
     C: GenericConfig<D, F = F>,
@@ -44,7 +47,8 @@ pub fn verify_stark_proof_circuit<
     proof_with_pis: StarkProofWithPublicInputsTarget<D>,
     inner_config: &StarkConfig,
     min_degree_bits_to_support: Option<usize>,
-) where
+) -> Vec<Target>
+where
     C::Hasher: AlgebraicHasher<F>,
 {
     assert_eq!(proof_with_pis.public_inputs.len(), S::PUBLIC_INPUTS);
@@ -68,6 +72,8 @@ pub fn verify_stark_proof_circuit<
         max_degree_bits_to_support,
         min_degree_bits_to_support,
     );
+
+    proof_with_pis.public_inputs
 }
 
 /// Recursively verifies an inner STARK proof.
@@ -108,15 +114,6 @@ pub fn verify_stark_proof_with_challenges_circuit<
         quotient_polys,
     } = &proof.openings;
 
-    let vars = S::EvaluationFrameTarget::from_values(
-        local_values,
-        next_values,
-        &public_inputs
-            .iter()
-            .map(|&t| builder.convert_to_ext(t))
-            .collect::<Vec<_>>(),
-    );
-
     // degree_bits should be nonzero.
     let _ = builder.inverse(proof.degree_bits);
 
@@ -124,6 +121,24 @@ pub fn verify_stark_proof_with_challenges_circuit<
     let degree = builder.exp(two, proof.degree_bits, max_num_of_bits_in_degree);
     let degree_bits_vec = builder.split_le(degree, max_num_of_bits_in_degree);
 
+    // Periodic columns are not committed, so we evaluate them in the circuit.
+    let (periodic_local_values, periodic_next_values): (Vec<_>, Vec<_>) = stark
+        .periodic_columns()
+        .iter()
+        .map(|column| {
+            column.eval_local_and_next_circuit(builder, challenges.stark_zeta, &degree_bits_vec)
+        })
+        .unzip();
+
+    let vars = S::EvaluationFrameTarget::from_values(
+        &[local_values.as_slice(), &periodic_local_values].concat(),
+        &[next_values.as_slice(), &periodic_next_values].concat(),
+        &public_inputs
+            .iter()
+            .map(|&t| builder.convert_to_ext(t))
+            .collect::<Vec<_>>(),
+    );
+
     let zeta_pow_deg = builder.exp_extension_from_bits(challenges.stark_zeta, &degree_bits_vec);
     let z_h_zeta = builder.sub_extension(zeta_pow_deg, one);
     let degree_ext = builder.convert_to_ext(degree);
@@ -296,7 +311,7 @@ pub fn add_virtual_stark_proof<F: RichField + Extendable<D>, S: Stark<F, D>, con
     let fri_params = config.fri_params(degree_bits);
     let cap_height = fri_params.config.cap_height;
 
-    let num_leaves_per_oracle = once(S::COLUMNS)
+    let num_leaves_per_oracle = once(stark.num_trace_columns())
         .chain(
             (stark.uses_lookups() || stark.requires_ctls())
                 .then(|| stark.num_lookup_helper_columns(config) + num_ctl_helper_zs),
@@ -337,8 +352,8 @@ fn add_virtual_stark_opening_set<F: RichField + Extendable<D>, S: Stark<F, D>, c
     config: &StarkConfig,
 ) -> StarkOpeningSetTarget<D> {
     StarkOpeningSetTarget {
-        local_values: builder.add_virtual_extension_targets(S::COLUMNS),
-        next_values: builder.add_virtual_extension_targets(S::COLUMNS),
+        local_values: builder.add_virtual_extension_targets(stark.num_trace_columns()),
+        next_values: builder.add_virtual_extension_targets(stark.num_trace_columns()),
         auxiliary_polys: (stark.uses_lookups() || stark.requires_ctls()).then(|| {
             builder.add_virtual_extension_targets(
                 stark.num_lookup_helper_columns(config) + num_ctl_helper_zs,
@@ -393,6 +408,13 @@ where
 
 /// Set the targets in a [`StarkProofTarget`] to their corresponding values in a
 /// [`StarkProof`].
+///
+/// The proof may contain more FRI query rounds than the target, for instance if it was generated
+/// with a larger `num_query_rounds`. Only the first rounds are then verified in-circuit, and the
+/// extra rounds are ignored. Since query indices are sampled after everything else has been
+/// observed, the first rounds are exactly those the circuit's config would have queried, so
+/// verification remains sound, but only at the security level of the circuit's config rather than
+/// that of the proof.
 pub fn set_stark_proof_target<F, C: GenericConfig<D, F = F>, W, const D: usize>(
     witness: &mut W,
     proof_target: &StarkProofTarget<D>,
@@ -428,7 +450,11 @@ where
         witness.set_cap_target(auxiliary_polys_cap_target, auxiliary_polys_cap)?;
     }
 
-    set_fri_proof_target(witness, &proof_target.opening_proof, &proof.opening_proof)
+    set_fri_proof_target_allowing_extra_query_rounds(
+        witness,
+        &proof_target.opening_proof,
+        &proof.opening_proof,
+    )
 }
 
 /// Utility function to check that all lookups data wrapped in `Option`s are `Some` iff
@@ -6,6 +6,7 @@ use alloc::{vec, vec::Vec};
 
 use plonky2::field::extension::{Extendable, FieldExtension};
 use plonky2::field::packed::PackedField;
+use plonky2::field::polynomial::PolynomialValues;
 use plonky2::field::types::Field;
 use plonky2::fri::structure::{
     FriBatchInfo, FriBatchInfoTarget, FriInstanceInfo, FriInstanceInfoTarget, FriOracleInfo,
@@ -20,10 +21,11 @@ use crate::config::StarkConfig;
 use crate::constraint_consumer::{ConstraintConsumer, RecursiveConstraintConsumer};
 use crate::evaluation_frame::StarkEvaluationFrame;
 use crate::lookup::Lookup;
+use crate::periodic_column::PeriodicColumn;
 
 /// Represents a STARK system.
 pub trait Stark<F: RichField + Extendable<D>, const D: usize>: Sync {
-    /// The total number of columns in the trace.
+    /// The total number of columns in the trace, including periodic columns.
     const COLUMNS: usize = Self::EvaluationFrameTarget::COLUMNS;
     /// The total number of public inputs.
     const PUBLIC_INPUTS: usize = Self::EvaluationFrameTarget::PUBLIC_INPUTS;
@@ -107,9 +109,10 @@ pub trait Stark<F: RichField + Extendable<D>, const D: usize>: Sync {
         config: &StarkConfig,
     ) -> FriInstanceInfo<F, D> {
         let mut oracles = vec![];
-        let trace_info = FriPolynomialInfo::from_range(oracles.len(), 0..Self::COLUMNS);
+        let num_trace_columns = self.num_trace_columns();
+        let trace_info = FriPolynomialInfo::from_range(oracles.len(), 0..num_trace_columns);
         oracles.push(FriOracleInfo {
-            num_polys: Self::COLUMNS,
+            num_polys: num_trace_columns,
             blinding: false,
         });
 
@@ -182,9 +185,10 @@ pub trait Stark<F: RichField + Extendable<D>, const D: usize>: Sync {
         config: &StarkConfig,
     ) -> FriInstanceInfoTarget<D> {
         let mut oracles = vec![];
-        let trace_info = FriPolynomialInfo::from_range(oracles.len(), 0..Self::COLUMNS);
+        let num_trace_columns = self.num_trace_columns();
+        let trace_info = FriPolynomialInfo::from_range(oracles.len(), 0..num_trace_columns);
         oracles.push(FriOracleInfo {
-            num_polys: Self::COLUMNS,
+            num_polys: num_trace_columns,
             blinding: false,
         });
 
@@ -248,6 +252,22 @@ pub trait Stark<F: RichField + Extendable<D>, const D: usize>: Sync {
         FriInstanceInfoTarget { oracles, batches }
     }
 
+    /// Outputs the periodic columns of this STARK. They occupy the last columns of the evaluation
+    /// frame, after the columns of the committed trace, and are computed by both the prover and
+    /// the verifier rather than being part of the witness. As such, the trace given to the prover
+    /// only contains the first [`Stark::num_trace_columns`] columns.
+    ///
+    /// Periodic columns cannot be referenced by lookups or cross-table lookups.
+    fn periodic_columns(&self) -> Vec<PeriodicColumn<F>> {
+        vec![]
+    }
+
+    /// Outputs the number of columns of the committed trace, i.e. [`Stark::COLUMNS`] minus the
+    /// number of periodic columns.
+    fn num_trace_columns(&self) -> usize {
+        Self::COLUMNS - self.periodic_columns().len()
+    }
+
     /// Outputs all the [`Lookup`] this STARK table needs to perform across its columns.
     fn lookups(&self) -> Vec<Lookup<F>> {
         vec![]
@@ -277,3 +297,15 @@ pub trait Stark<F: RichField + Extendable<D>, const D: usize>: Sync {
         false
     }
 }
+
+/// An optional extension of [`Stark`] for systems able to generate their own execution trace.
+///
+/// This gives tooling a uniform way of producing traces for any STARK, and allows proving
+/// directly from the trace inputs with [`prove_from_inputs`](crate::prover::prove_from_inputs).
+pub trait TraceGenerator<F: RichField + Extendable<D>, const D: usize>: Stark<F, D> {
+    /// The inputs needed to generate a trace for this STARK.
+    type TraceInputs;
+
+    /// Generates the trace, in column-major form, corresponding to the provided inputs.
+    fn generate_trace(&self, inputs: &Self::TraceInputs) -> Vec<PolynomialValues<F>>;
+}
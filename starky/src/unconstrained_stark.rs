@@ -114,10 +114,17 @@ mod tests {
 
         let stark = S::new(num_rows);
         let trace = stark.generate_trace();
-        let proof =
-            prove::<F, C, S, D>(stark, &config, trace, &[], None, &mut TimingTree::default())?;
-
-        verify_stark_proof(stark, proof, &config, None)
+        let proof = prove::<F, C, S, D>(
+            stark,
+            &config,
+            trace,
+            &[],
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+
+        verify_stark_proof(stark, proof, &config, None, &mut TimingTree::default())
     }
 
     #[test]
@@ -157,9 +164,22 @@ mod tests {
 
         let stark = S::new(num_rows);
         let trace = stark.generate_trace();
-        let proof =
-            prove::<F, C, S, D>(stark, &config, trace, &[], None, &mut TimingTree::default())?;
-        verify_stark_proof(stark, proof.clone(), &config, None)?;
+        let proof = prove::<F, C, S, D>(
+            stark,
+            &config,
+            trace,
+            &[],
+            None,
+            &mut TimingTree::default(),
+            None,
+        )?;
+        verify_stark_proof(
+            stark,
+            proof.clone(),
+            &config,
+            None,
+            &mut TimingTree::default(),
+        )?;
 
         recursive_proof::<F, C, S, C, D>(stark, proof, &config, true)
     }
@@ -3,19 +3,21 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-use itertools::Itertools;
 use plonky2::field::polynomial::PolynomialValues;
 use plonky2::field::types::Field;
-use plonky2::util::transpose;
+use plonky2::util::transpose_rectangular;
 
 /// A helper function to transpose a row-wise trace and put it in the format that `prove` expects.
 pub fn trace_rows_to_poly_values<F: Field, const COLUMNS: usize>(
     trace_rows: Vec<[F; COLUMNS]>,
 ) -> Vec<PolynomialValues<F>> {
-    let trace_row_vecs = trace_rows.into_iter().map(|row| row.to_vec()).collect_vec();
-    let trace_col_vecs: Vec<Vec<F>> = transpose(&trace_row_vecs);
-    trace_col_vecs
-        .into_iter()
-        .map(|column| PolynomialValues::new(column))
+    let num_rows = trace_rows.len();
+    if num_rows == 0 {
+        return Vec::new();
+    }
+    let trace_cols = transpose_rectangular(trace_rows.as_flattened(), num_rows, COLUMNS);
+    trace_cols
+        .chunks_exact(num_rows)
+        .map(|column| PolynomialValues::new(column.to_vec()))
         .collect()
 }
@@ -16,6 +16,8 @@ use plonky2::hash::merkle_tree::MerkleCap;
 use plonky2::iop::challenger::Challenger;
 use plonky2::plonk::config::GenericConfig;
 use plonky2::plonk::plonk_common::reduce_with_powers;
+use plonky2::timed;
+use plonky2::util::timing::TimingTree;
 
 use crate::config::StarkConfig;
 use crate::constraint_consumer::ConstraintConsumer;
@@ -37,16 +39,21 @@ pub fn verify_stark_proof<
     proof_with_pis: StarkProofWithPublicInputs<F, C, D>,
     config: &StarkConfig,
     verifier_circuit_fri_params: Option<FriParams>,
+    timing: &mut TimingTree,
 ) -> Result<()> {
     ensure!(proof_with_pis.public_inputs.len() == S::PUBLIC_INPUTS);
     let mut challenger = Challenger::<F, C::Hasher>::new();
 
-    let challenges = proof_with_pis.get_challenges(
-        &mut challenger,
-        None,
-        false,
-        config,
-        verifier_circuit_fri_params,
+    let challenges = timed!(
+        timing,
+        "derive challenges",
+        proof_with_pis.get_challenges(
+            &mut challenger,
+            None,
+            false,
+            config,
+            verifier_circuit_fri_params,
+        )
     );
 
     verify_stark_proof_with_challenges(
@@ -56,6 +63,7 @@ pub fn verify_stark_proof<
         None,
         &proof_with_pis.public_inputs,
         config,
+        timing,
     )
 }
 
@@ -70,6 +78,7 @@ pub fn verify_stark_proof_with_challenges<F, C, S, const D: usize>(
     ctl_vars: Option<&[CtlCheckVars<F, F::Extension, F::Extension, D>]>,
     public_inputs: &[F],
     config: &StarkConfig,
+    timing: &mut TimingTree,
 ) -> Result<()>
 where
     F: RichField + Extendable<D>,
@@ -96,6 +105,73 @@ where
         num_ctl_z_polys,
     )?;
 
+    let degree_bits = proof.recover_degree_bits(config);
+
+    timed!(
+        timing,
+        "check opening consistency",
+        check_opening_consistency(
+            stark,
+            proof,
+            challenges,
+            ctl_vars,
+            public_inputs,
+            config,
+            degree_bits
+        )
+    )?;
+
+    let merkle_caps = once(proof.trace_cap.clone())
+        .chain(proof.auxiliary_polys_cap.clone())
+        .chain(proof.quotient_polys_cap.clone())
+        .collect_vec();
+
+    let num_ctl_zs = ctl_vars
+        .map(|vars| {
+            vars.iter()
+                .map(|ctl| ctl.helper_columns.len())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    timed!(
+        timing,
+        "verify FRI proof",
+        verify_fri_proof::<F, C, D>(
+            &stark.fri_instance(
+                challenges.stark_zeta,
+                F::primitive_root_of_unity(degree_bits),
+                num_ctl_polys,
+                num_ctl_zs,
+                config,
+            ),
+            &proof.openings.to_fri_openings(),
+            &challenges.fri_challenges,
+            &merkle_caps,
+            &proof.opening_proof,
+            &config.fri_params(degree_bits),
+        )
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the openings at `zeta` satisfy the STARK's constraints, i.e. that the vanishing
+/// polynomial agrees with `Z_H(zeta)` times the opened quotient polynomials.
+fn check_opening_consistency<F, C, S, const D: usize>(
+    stark: &S,
+    proof: &StarkProof<F, C, D>,
+    challenges: &StarkProofChallenges<F, D>,
+    ctl_vars: Option<&[CtlCheckVars<F, F::Extension, F::Extension, D>]>,
+    public_inputs: &[F],
+    config: &StarkConfig,
+    degree_bits: usize,
+) -> Result<()>
+where
+    F: RichField + Extendable<D>,
+    C: GenericConfig<D, F = F>,
+    S: Stark<F, D>,
+{
     let StarkOpeningSet {
         local_values,
         next_values,
@@ -105,9 +181,16 @@ where
         quotient_polys,
     } = &proof.openings;
 
+    // Periodic columns are not committed, so we evaluate them ourselves.
+    let (periodic_local_values, periodic_next_values): (Vec<_>, Vec<_>) = stark
+        .periodic_columns()
+        .iter()
+        .map(|column| column.eval_local_and_next::<D>(challenges.stark_zeta, degree_bits))
+        .unzip();
+
     let vars = S::EvaluationFrame::from_values(
-        local_values,
-        next_values,
+        &[local_values.as_slice(), &periodic_local_values].concat(),
+        &[next_values.as_slice(), &periodic_next_values].concat(),
         &public_inputs
             .iter()
             .copied()
@@ -115,7 +198,6 @@ where
             .collect::<Vec<_>>(),
     );
 
-    let degree_bits = proof.recover_degree_bits(config);
     let (l_0, l_last) = eval_l_0_and_l_last(degree_bits, challenges.stark_zeta);
     let last = F::primitive_root_of_unity(degree_bits).inverse();
     let z_last = challenges.stark_zeta - last.into();
@@ -184,34 +266,6 @@ where
         );
     }
 
-    let merkle_caps = once(proof.trace_cap.clone())
-        .chain(proof.auxiliary_polys_cap.clone())
-        .chain(proof.quotient_polys_cap.clone())
-        .collect_vec();
-
-    let num_ctl_zs = ctl_vars
-        .map(|vars| {
-            vars.iter()
-                .map(|ctl| ctl.helper_columns.len())
-                .collect::<Vec<_>>()
-        })
-        .unwrap_or_default();
-
-    verify_fri_proof::<F, C, D>(
-        &stark.fri_instance(
-            challenges.stark_zeta,
-            F::primitive_root_of_unity(degree_bits),
-            num_ctl_polys,
-            num_ctl_zs,
-            config,
-        ),
-        &proof.openings.to_fri_openings(),
-        &challenges.fri_challenges,
-        &merkle_caps,
-        &proof.opening_proof,
-        &config.fri_params(degree_bits),
-    )?;
-
     Ok(())
 }
 
@@ -260,8 +314,8 @@ where
             || quotient_polys_cap.as_ref().map(|q| q.height()) == Some(cap_height)
     );
 
-    ensure!(local_values.len() == S::COLUMNS);
-    ensure!(next_values.len() == S::COLUMNS);
+    ensure!(local_values.len() == stark.num_trace_columns());
+    ensure!(next_values.len() == stark.num_trace_columns());
     ensure!(if let Some(quotient_polys) = quotient_polys {
         quotient_polys.len() == stark.num_quotient_polys(config)
     } else {
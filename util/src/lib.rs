@@ -9,6 +9,7 @@ use core::mem::size_of;
 use core::ptr::{swap, swap_nonoverlapping};
 
 use crate::transpose_util::transpose_in_place_square;
+pub use crate::transpose_util::transpose_rectangular;
 
 mod transpose_util;
 
@@ -291,7 +292,7 @@ mod tests {
     use rand::rngs::OsRng;
     use rand::Rng;
 
-    use crate::{log2_ceil, log2_strict};
+    use crate::{log2_ceil, log2_strict, transpose_rectangular};
 
     #[test]
     fn test_reverse_index_bits() {
@@ -395,4 +396,19 @@ mod tests {
 
         out.into_iter().map(|x| x.unwrap()).collect()
     }
+
+    #[test]
+    fn test_transpose_rectangular() {
+        // Cover shapes smaller than, equal to, and not a multiple of the block size.
+        for (rows, cols) in [(0, 0), (1, 1), (1, 7), (7, 1), (16, 16), (37, 5), (100, 50)] {
+            let src = (0..rows * cols)
+                .map(|_| OsRng.gen::<u32>())
+                .collect::<Vec<_>>();
+            let naive = (0..cols)
+                .flat_map(|j| (0..rows).map(move |i| (i, j)))
+                .map(|(i, j)| src[i * cols + j])
+                .collect::<Vec<_>>();
+            assert_eq!(transpose_rectangular(&src, rows, cols), naive);
+        }
+    }
 }
@@ -1,7 +1,13 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
 use core::ptr::swap;
 
 const LB_BLOCK_SIZE: usize = 3;
 
+/// Side length of the blocks used by `transpose_rectangular`.
+const RECTANGULAR_BLOCK_SIZE: usize = 16;
+
 /// Transpose square matrix in-place
 /// The matrix is of size `1 << lb_size` by `1 << lb_size`. It occupies
 /// `M[i, j] == arr[(i + x << lb_stride) + j + x]` for `0 <= i, j < 1 << lb_size`. The transposition
@@ -110,3 +116,32 @@ pub(crate) unsafe fn transpose_in_place_square<T>(
         transpose_in_place_square(arr, lb_stride, lb_block_size, x + block_size);
     }
 }
+
+/// Transposes the `rows` by `cols` matrix stored row-major in `src`, returning the `cols` by `rows`
+/// matrix, also row-major. The matrix is traversed in small square blocks so that both the rows
+/// read and the rows written by a block stay in cache, which is considerably faster than a naive
+/// transpose for tall matrices such as execution traces.
+pub fn transpose_rectangular<T: Copy>(src: &[T], rows: usize, cols: usize) -> Vec<T> {
+    assert_eq!(
+        src.len(),
+        rows * cols,
+        "Matrix should have rows * cols elements"
+    );
+    let Some(&first) = src.first() else {
+        return Vec::new();
+    };
+
+    let mut dst = vec![first; src.len()];
+    for row_start in (0..rows).step_by(RECTANGULAR_BLOCK_SIZE) {
+        let row_end = min(row_start + RECTANGULAR_BLOCK_SIZE, rows);
+        for col_start in (0..cols).step_by(RECTANGULAR_BLOCK_SIZE) {
+            let col_end = min(col_start + RECTANGULAR_BLOCK_SIZE, cols);
+            for i in row_start..row_end {
+                for j in col_start..col_end {
+                    dst[j * rows + i] = src[i * cols + j];
+                }
+            }
+        }
+    }
+    dst
+}